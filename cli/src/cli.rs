@@ -1,6 +1,12 @@
-use crate::parser::ProgramInfo;
+use crate::parser::{ProgramInfo, QueueManifest};
 use clap::{Arg, ArgGroup, Command};
-use clockwork_client::{queue::objects::Trigger, webhook::objects::HttpMethod};
+use clockwork_client::{
+    queue::{
+        objects::{Role, Trigger},
+        TriggerType,
+    },
+    webhook::objects::HttpMethod,
+};
 use clockwork_utils::InstructionData;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair};
 
@@ -12,6 +18,13 @@ pub enum CliCommand {
         base_url: String,
     },
 
+    // Apply commands
+    Apply {
+        dry_run: bool,
+        prune: bool,
+        queues: Vec<QueueManifest>,
+    },
+
     // Config commands
     ConfigGet,
     ConfigSet {
@@ -22,7 +35,14 @@ pub enum CliCommand {
 
     // Crontab
     Crontab {
+        count: bool,
+        diff: Option<String>,
+        from: Option<i64>,
+        json: bool,
+        limit: Option<usize>,
         schedule: String,
+        timezone: Option<String>,
+        until: Option<i64>,
     },
 
     // Delegation
@@ -59,30 +79,74 @@ pub enum CliCommand {
     },
 
     // Queue commands
+    QueueAccept {
+        creator: Pubkey,
+        id: String,
+    },
     QueueCreate {
         id: String,
-        kickoff_instruction: InstructionData,
+        kickoff_instructions: Vec<InstructionData>,
         trigger: Trigger,
     },
     QueueDelete {
+        queues: QueueSelector,
+    },
+    QueueFund {
+        amount: u64,
         id: String,
     },
     QueueGet {
         id: String,
     },
-    QueuePause {
+    QueueGrant {
+        delegate: Pubkey,
+        id: String,
+        role: Role,
+    },
+    QueueHistory {
+        id: String,
+    },
+    QueueList {
+        authority: Option<Pubkey>,
+        paused: Option<bool>,
+        program_id: Option<Pubkey>,
+        trigger_type: Option<TriggerType>,
+    },
+    QueueMigrate {
         id: String,
     },
+    QueuePause {
+        queues: QueueSelector,
+    },
     QueueResume {
+        queues: QueueSelector,
+    },
+    QueueRevoke {
+        delegate: Pubkey,
         id: String,
+        role: Role,
     },
     QueueStop {
+        queues: QueueSelector,
+    },
+    QueueTransfer {
         id: String,
+        new_authority: Pubkey,
     },
     QueueUpdate {
+        address_lookup_tables: Option<Vec<Pubkey>>,
+        compute_unit_limit: Option<u32>,
+        compute_unit_price: Option<u64>,
+        end_at: Option<i64>,
+        exec_history_limit: Option<u64>,
         id: String,
+        max_compute_unit_price: Option<u64>,
+        max_runs: Option<u64>,
+        min_balance: Option<u64>,
         rate_limit: Option<u64>,
         schedule: Option<String>,
+        start_at: Option<i64>,
+        timezone: Option<String>,
     },
 
     // Registry
@@ -106,12 +170,49 @@ pub enum CliCommand {
     },
 }
 
+/// Selects the queues a command should act on.
+#[derive(Debug, PartialEq)]
+pub enum QueueSelector {
+    /// A single queue, by id.
+    Id(String),
+    /// A list of queues, by id.
+    Ids(Vec<String>),
+    /// All of the authority's queues with ids matching a glob pattern.
+    Match(String),
+}
+
 pub fn app() -> Command<'static> {
     Command::new("Clockwork")
         .bin_name("clockwork")
         .about("An automation engine for the Solana blockchain")
         .version(version!())
         .arg_required_else_help(true)
+        .subcommand(
+            Command::new("apply")
+                .about("Create, update, and delete queues to match a manifest")
+                .arg(
+                    Arg::new("manifest")
+                        .index(1)
+                        .value_name("FILEPATH")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Filepath to a YAML or JSON manifest describing your queues"),
+                )
+                .arg(
+                    Arg::new("dry_run")
+                        .long("dry_run")
+                        .takes_value(false)
+                        .required(false)
+                        .help("Print the planned changes without sending any transactions"),
+                )
+                .arg(
+                    Arg::new("prune")
+                        .long("prune")
+                        .takes_value(false)
+                        .required(false)
+                        .help("Delete your queues which are not listed in the manifest"),
+                ),
+        )
         .subcommand(
             Command::new("config")
                 .about("Manage the Clockwork network config")
@@ -155,6 +256,55 @@ pub fn app() -> Command<'static> {
                         .takes_value(true)
                         .required(true)
                         .help("The schedule to generate a cron table for"),
+                )
+                .arg(
+                    Arg::new("timezone")
+                        .long("timezone")
+                        .value_name("TIMEZONE")
+                        .takes_value(true)
+                        .help("The time zone to evaluate the schedule in, one of the IANA zones supported by clockwork_cron (e.g. America/New_York)"),
+                )
+                .arg(
+                    Arg::new("from")
+                        .long("from")
+                        .value_name("UNIX_TS")
+                        .takes_value(true)
+                        .help("The unix timestamp to generate the table after (defaults to the cluster's current time)"),
+                )
+                .arg(
+                    Arg::new("until")
+                        .long("until")
+                        .value_name("UNIX_TS")
+                        .takes_value(true)
+                        .help("The unix timestamp to generate the table until, inclusive"),
+                )
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .value_name("LIMIT")
+                        .takes_value(true)
+                        .help("The maximum number of fire times to print (defaults to 9 if no --until is given)"),
+                )
+                .arg(
+                    Arg::new("diff")
+                        .long("diff")
+                        .value_name("SCHEDULE")
+                        .takes_value(true)
+                        .requires("until")
+                        .help("Only include the fire times which are not in this other schedule"),
+                )
+                .arg(
+                    Arg::new("count")
+                        .long("count")
+                        .takes_value(false)
+                        .requires("until")
+                        .help("Print the number of fire times rather than the times themselves"),
+                )
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .takes_value(false)
+                        .help("Print the output as JSON"),
                 ),
         )
         .subcommand(
@@ -294,6 +444,26 @@ pub fn app() -> Command<'static> {
             Command::new("queue")
                 .about("Manage your transaction queues")
                 .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("accept")
+                        .about("Accept ownership of a queue which was transferred to you")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The id of the queue to accept"),
+                        )
+                        .arg(
+                            Arg::new("creator")
+                                .long("creator")
+                                .short('c')
+                                .value_name("PUBKEY")
+                                .takes_value(true)
+                                .required(true)
+                                .help("The address of the authority which created the queue"),
+                        ),
+                )
                 .subcommand(
                     Command::new("create")
                         .about("Create a new queue")
@@ -313,7 +483,7 @@ pub fn app() -> Command<'static> {
                                 .value_name("FILEPATH")
                                 .takes_value(true)
                                 .required(true)
-                                .help("Filepath to a description of the kickoff instruction"),
+                                .help("Filepath to a description of the kickoff instruction(s)"),
                         )
                         .arg(
                             Arg::new("account")
@@ -323,6 +493,68 @@ pub fn app() -> Command<'static> {
                                 .takes_value(true)
                                 .help("An account-based trigger"),
                         )
+                        .arg(
+                            Arg::new("offset")
+                                .long("offset")
+                                .value_name("OFFSET")
+                                .takes_value(true)
+                                .help("The byte offset of the account data to watch"),
+                        )
+                        .arg(
+                            Arg::new("size")
+                                .long("size")
+                                .value_name("SIZE")
+                                .takes_value(true)
+                                .requires("account")
+                                .help("The number of bytes of account data to watch. If omitted, the whole account is watched"),
+                        )
+                        .arg(
+                            Arg::new("condition")
+                                .long("condition")
+                                .value_name("ADDRESS")
+                                .takes_value(true)
+                                .requires_all(&["value_type", "operator", "operand"])
+                                .help("A trigger on an integer value in an account's data, which fires each time the condition starts to hold"),
+                        )
+                        .arg(
+                            Arg::new("value_type")
+                                .long("value_type")
+                                .value_name("TYPE")
+                                .takes_value(true)
+                                .requires("condition")
+                                .possible_values([
+                                    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64",
+                                    "i128",
+                                ])
+                                .help("The integer type of the watched value"),
+                        )
+                        .arg(
+                            Arg::new("endianness")
+                                .long("endianness")
+                                .value_name("ENDIANNESS")
+                                .takes_value(true)
+                                .requires("condition")
+                                .possible_values(["little", "big"])
+                                .help("The byte order of the watched value. Defaults to little"),
+                        )
+                        .arg(
+                            Arg::new("operator")
+                                .long("operator")
+                                .value_name("OPERATOR")
+                                .takes_value(true)
+                                .requires("condition")
+                                .possible_values(["eq", "ne", "gt", "ge", "lt", "le"])
+                                .help("The comparison to apply between the watched value and the operand"),
+                        )
+                        .arg(
+                            Arg::new("operand")
+                                .long("operand")
+                                .value_name("VALUE")
+                                .takes_value(true)
+                                .allow_hyphen_values(true)
+                                .requires("condition")
+                                .help("The operand to compare the watched value against"),
+                        )
                         .arg(
                             Arg::new("cron")
                                 .long("cron")
@@ -338,20 +570,141 @@ pub fn app() -> Command<'static> {
                                 .takes_value(false)
                                 .help("An immediate trigger"),
                         )
+                        .arg(
+                            Arg::new("slot")
+                                .long("slot")
+                                .value_name("SLOT")
+                                .takes_value(true)
+                                .help("A slot-based trigger"),
+                        )
+                        .arg(
+                            Arg::new("epoch")
+                                .long("epoch")
+                                .value_name("EPOCH")
+                                .takes_value(true)
+                                .help("An epoch-based trigger"),
+                        )
+                        .arg(
+                            Arg::new("after_queue")
+                                .long("after_queue")
+                                .value_name("ADDRESS")
+                                .takes_value(true)
+                                .help("A trigger on the completed runs of another queue"),
+                        )
+                        .arg(
+                            Arg::new("interval")
+                                .long("interval")
+                                .value_name("SECONDS")
+                                .takes_value(true)
+                                .help("An interval-based trigger"),
+                        )
+                        .arg(
+                            Arg::new("timestamp")
+                                .long("timestamp")
+                                .value_name("UNIX_TS")
+                                .takes_value(true)
+                                .help("A one-shot trigger at a unix timestamp"),
+                        )
+                        .arg(
+                            Arg::new("timezone")
+                                .long("timezone")
+                                .value_name("TIMEZONE")
+                                .takes_value(true)
+                                .requires("cron")
+                                .help("The time zone to evaluate the cron schedule in, one of the IANA zones supported by clockwork_cron (e.g. America/New_York)"),
+                        )
+                        .arg(
+                            Arg::new("start_at")
+                                .long("start_at")
+                                .value_name("UNIX_TS")
+                                .takes_value(true)
+                                .requires("cron")
+                                .help("The unix timestamp before which the cron schedule may not kick off the queue"),
+                        )
+                        .arg(
+                            Arg::new("end_at")
+                                .long("end_at")
+                                .value_name("UNIX_TS")
+                                .takes_value(true)
+                                .requires("cron")
+                                .help("The unix timestamp after which the cron schedule may no longer kick off the queue"),
+                        )
+                        .arg(
+                            Arg::new("max_runs")
+                                .long("max_runs")
+                                .value_name("COUNT")
+                                .takes_value(true)
+                                .requires("cron")
+                                .help("The maximum number of times the cron schedule may kick off the queue"),
+                        )
                         .group(
                             ArgGroup::new("trigger")
-                                .args(&["account", "cron", "immediate"])
+                                .args(&[
+                                    "account",
+                                    "condition",
+                                    "cron",
+                                    "immediate",
+                                    "slot",
+                                    "epoch",
+                                    "after_queue",
+                                    "interval",
+                                    "timestamp",
+                                ])
                                 .required(true),
                         ),
                 )
                 .subcommand(
-                    Command::new("delete").about("Delete a queue").arg(
-                        Arg::new("id")
-                            .index(1)
-                            .takes_value(true)
-                            .required(false)
-                            .help("The id of the queue to delete"),
-                    ),
+                    Command::new("delete")
+                        .about("Delete a queue")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(false)
+                                .help("The id of the queue to delete"),
+                        )
+                        .arg(
+                            Arg::new("match")
+                                .long("match")
+                                .short('m')
+                                .value_name("GLOB")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Delete all of your queues with ids matching a glob pattern"),
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .short('f')
+                                .value_name("FILEPATH")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Delete the queues with ids listed in a file, one per line"),
+                        )
+                        .group(
+                            ArgGroup::new("queues")
+                                .args(&["id", "match", "file"])
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    Command::new("fund")
+                        .about("Fund a queue")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(false)
+                                .help("The id of the queue to fund"),
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .long("amount")
+                                .short('a')
+                                .takes_value(true)
+                                .required(true)
+                                .help("The number of lamports to transfer to the queue"),
+                        ),
                 )
                 .subcommand(
                     Command::new("get").about("Lookup a queue").arg(
@@ -363,31 +716,257 @@ pub fn app() -> Command<'static> {
                     ),
                 )
                 .subcommand(
-                    Command::new("pause").about("Pause a queue").arg(
-                        Arg::new("id")
-                            .index(1)
-                            .takes_value(true)
-                            .required(false)
-                            .help("The id of the queue to pause"),
-                    ),
+                    Command::new("grant")
+                        .about("Grant a role on a queue to a delegate")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The id of the queue to grant the role on"),
+                        )
+                        .arg(
+                            Arg::new("delegate")
+                                .index(2)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The address of the delegate"),
+                        )
+                        .arg(
+                            Arg::new("role")
+                                .long("role")
+                                .short('r')
+                                .takes_value(true)
+                                .possible_values(["pauser", "updater", "withdrawer"])
+                                .required(true)
+                                .help("The role to grant"),
+                        ),
                 )
                 .subcommand(
-                    Command::new("resume").about("Resume a queue").arg(
-                        Arg::new("id")
-                            .index(1)
-                            .takes_value(true)
-                            .required(false)
-                            .help("The id of the queue to resume"),
-                    ),
+                    Command::new("history")
+                        .about("Lookup the recent executions of a queue")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(false)
+                                .help("The id of the queue to lookup"),
+                        ),
                 )
                 .subcommand(
-                    Command::new("stop").about("Stop a queue").arg(
-                        Arg::new("id")
-                            .index(1)
-                            .takes_value(true)
-                            .required(false)
-                            .help("The id of the queue to stop"),
-                    ),
+                    Command::new("list")
+                        .about("List your queues")
+                        .arg(
+                            Arg::new("authority")
+                                .long("authority")
+                                .short('a')
+                                .value_name("PUBKEY")
+                                .takes_value(true)
+                                .required(false)
+                                .help("List the queues of another authority"),
+                        )
+                        .arg(
+                            Arg::new("paused")
+                                .long("paused")
+                                .takes_value(false)
+                                .required(false)
+                                .help("Only list paused queues"),
+                        )
+                        .arg(
+                            Arg::new("active")
+                                .long("active")
+                                .takes_value(false)
+                                .required(false)
+                                .help("Only list queues which are not paused"),
+                        )
+                        .arg(
+                            Arg::new("program_id")
+                                .long("program_id")
+                                .short('p')
+                                .value_name("PUBKEY")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Only list queues with an instruction targeting a program"),
+                        )
+                        .arg(
+                            Arg::new("trigger")
+                                .long("trigger")
+                                .short('t')
+                                .value_name("TYPE")
+                                .takes_value(true)
+                                .possible_values([
+                                    "account",
+                                    "condition",
+                                    "cron",
+                                    "epoch",
+                                    "immediate",
+                                    "interval",
+                                    "queue",
+                                    "slot",
+                                    "timestamp",
+                                ])
+                                .required(false)
+                                .help("Only list queues with a type of trigger"),
+                        )
+                        .group(ArgGroup::new("status").args(&["paused", "active"])),
+                )
+                .subcommand(
+                    Command::new("migrate")
+                        .about("Migrate a queue from a legacy layout to the current layout")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The id of the queue to migrate"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("pause")
+                        .about("Pause a queue")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(false)
+                                .help("The id of the queue to pause"),
+                        )
+                        .arg(
+                            Arg::new("match")
+                                .long("match")
+                                .short('m')
+                                .value_name("GLOB")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Pause all of your queues with ids matching a glob pattern"),
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .short('f')
+                                .value_name("FILEPATH")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Pause the queues with ids listed in a file, one per line"),
+                        )
+                        .group(
+                            ArgGroup::new("queues")
+                                .args(&["id", "match", "file"])
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    Command::new("resume")
+                        .about("Resume a queue")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(false)
+                                .help("The id of the queue to resume"),
+                        )
+                        .arg(
+                            Arg::new("match")
+                                .long("match")
+                                .short('m')
+                                .value_name("GLOB")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Resume all of your queues with ids matching a glob pattern"),
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .short('f')
+                                .value_name("FILEPATH")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Resume the queues with ids listed in a file, one per line"),
+                        )
+                        .group(
+                            ArgGroup::new("queues")
+                                .args(&["id", "match", "file"])
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    Command::new("revoke")
+                        .about("Revoke a role on a queue from a delegate")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The id of the queue to revoke the role on"),
+                        )
+                        .arg(
+                            Arg::new("delegate")
+                                .index(2)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The address of the delegate"),
+                        )
+                        .arg(
+                            Arg::new("role")
+                                .long("role")
+                                .short('r')
+                                .takes_value(true)
+                                .possible_values(["pauser", "updater", "withdrawer"])
+                                .required(true)
+                                .help("The role to revoke"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("stop")
+                        .about("Stop a queue")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(false)
+                                .help("The id of the queue to stop"),
+                        )
+                        .arg(
+                            Arg::new("match")
+                                .long("match")
+                                .short('m')
+                                .value_name("GLOB")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Stop all of your queues with ids matching a glob pattern"),
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .short('f')
+                                .value_name("FILEPATH")
+                                .takes_value(true)
+                                .required(false)
+                                .help("Stop the queues with ids listed in a file, one per line"),
+                        )
+                        .group(
+                            ArgGroup::new("queues")
+                                .args(&["id", "match", "file"])
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    Command::new("transfer")
+                        .about("Offer ownership of a queue to a new authority")
+                        .arg(
+                            Arg::new("id")
+                                .index(1)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The id of the queue to transfer"),
+                        )
+                        .arg(
+                            Arg::new("new_authority")
+                                .index(2)
+                                .takes_value(true)
+                                .required(true)
+                                .help("The address of the new authority, which must accept the transfer"),
+                        ),
                 )
                 .subcommand(
                     Command::new("update")
@@ -399,6 +978,54 @@ pub fn app() -> Command<'static> {
                                 .required(false)
                                 .help("The id of the queue to lookup"),
                         )
+                        .arg(
+                            Arg::new("address_lookup_tables")
+                                .long("address_lookup_tables")
+                                .short('a')
+                                .value_name("ADDRESS")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .required(false)
+                                .help("The address lookup tables to compress the queue's transactions with"),
+                        )
+                        .arg(
+                            Arg::new("compute_unit_limit")
+                                .long("compute_unit_limit")
+                                .value_name("UNITS")
+                                .takes_value(true)
+                                .required(false)
+                                .help("The compute unit limit to request for the queue's crank transactions"),
+                        )
+                        .arg(
+                            Arg::new("compute_unit_price")
+                                .long("compute_unit_price")
+                                .value_name("MICRO_LAMPORTS")
+                                .takes_value(true)
+                                .required(false)
+                                .help("The priority fee to pay per compute unit for the queue's crank transactions"),
+                        )
+                        .arg(
+                            Arg::new("max_compute_unit_price")
+                                .long("max_compute_unit_price")
+                                .value_name("MICRO_LAMPORTS")
+                                .takes_value(true)
+                                .required(false)
+                                .help("The maximum priority fee per compute unit to reimburse workers for"),
+                        )
+                        .arg(
+                            Arg::new("exec_history_limit")
+                                .long("exec_history_limit")
+                                .takes_value(true)
+                                .required(false)
+                                .help("The number of recent executions to record in the queue's history"),
+                        )
+                        .arg(
+                            Arg::new("min_balance")
+                                .long("min_balance")
+                                .takes_value(true)
+                                .required(false)
+                                .help("The number of lamports the queue must retain to continue cranking"),
+                        )
                         .arg(
                             Arg::new("rate_limit")
                                 .long("rate_limit")
@@ -416,6 +1043,38 @@ pub fn app() -> Command<'static> {
                                 .takes_value(true)
                                 .required(false)
                                 .help("The cron schedule of the queue"),
+                        )
+                        .arg(
+                            Arg::new("timezone")
+                                .long("timezone")
+                                .value_name("TIMEZONE")
+                                .takes_value(true)
+                                .requires("schedule")
+                                .help("The time zone to evaluate the cron schedule in, one of the IANA zones supported by clockwork_cron"),
+                        )
+                        .arg(
+                            Arg::new("start_at")
+                                .long("start_at")
+                                .value_name("UNIX_TS")
+                                .takes_value(true)
+                                .requires("schedule")
+                                .help("The unix timestamp before which the cron schedule may not kick off the queue"),
+                        )
+                        .arg(
+                            Arg::new("end_at")
+                                .long("end_at")
+                                .value_name("UNIX_TS")
+                                .takes_value(true)
+                                .requires("schedule")
+                                .help("The unix timestamp after which the cron schedule may no longer kick off the queue"),
+                        )
+                        .arg(
+                            Arg::new("max_runs")
+                                .long("max_runs")
+                                .value_name("COUNT")
+                                .takes_value(true)
+                                .requires("schedule")
+                                .help("The maximum number of times the cron schedule may kick off the queue"),
                         ),
                 ),
         )
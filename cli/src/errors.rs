@@ -20,6 +20,10 @@ pub enum CliError {
     FailedLocalnet(String),
     #[error("Invalid address")]
     InvalidAddress,
+    #[error("Invalid cron schedule: {0}")]
+    InvalidSchedule(String),
     #[error("Program file does not exist")]
     InvalidProgramFile,
+    #[error("Invalid manifest: {0}")]
+    InvalidManifest(String),
 }
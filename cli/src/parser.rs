@@ -1,6 +1,19 @@
-use crate::{cli::CliCommand, errors::CliError};
+use crate::{
+    cli::{CliCommand, QueueSelector},
+    errors::CliError,
+};
 use clap::ArgMatches;
-use clockwork_client::{queue::objects::Trigger, webhook::objects::HttpMethod};
+use clockwork_client::{
+    queue::{
+        objects::{
+            Endianness, Operator, Role, Trigger, ValueType, DEFAULT_COMPUTE_UNIT_LIMIT,
+            DEFAULT_RATE_LIMIT, MINIMUM_FEE,
+        },
+        TriggerType,
+    },
+    webhook::objects::HttpMethod,
+};
+use clockwork_cron::Schedule;
 use clockwork_utils::{AccountMetaData, InstructionData};
 use serde::{Deserialize as JsonDeserialize, Serialize as JsonSerialize};
 use solana_sdk::{
@@ -16,6 +29,7 @@ impl TryFrom<&ArgMatches> for CliCommand {
     fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
         match matches.subcommand() {
             Some(("api", matches)) => parse_api_command(matches),
+            Some(("apply", matches)) => parse_apply_command(matches),
             Some(("config", matches)) => parse_config_command(matches),
             Some(("crontab", matches)) => parse_crontab_command(matches),
             Some(("delegation", matches)) => parse_delegation_command(matches),
@@ -81,6 +95,14 @@ fn parse_api_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     }
 }
 
+fn parse_apply_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
+    Ok(CliCommand::Apply {
+        dry_run: matches.is_present("dry_run"),
+        prune: matches.is_present("prune"),
+        queues: parse_manifest_file("manifest", matches)?,
+    })
+}
+
 fn parse_config_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     match matches.subcommand() {
         Some(("get", _)) => Ok(CliCommand::ConfigGet {}),
@@ -97,7 +119,26 @@ fn parse_config_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
 
 fn parse_crontab_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     Ok(CliCommand::Crontab {
-        schedule: parse_string("schedule", matches)?,
+        count: matches.is_present("count"),
+        diff: matches
+            .is_present("diff")
+            .then(|| parse_schedule("diff", matches))
+            .transpose()?,
+        from: matches
+            .is_present("from")
+            .then(|| parse_i64("from", matches))
+            .transpose()?,
+        json: matches.is_present("json"),
+        limit: matches
+            .is_present("limit")
+            .then(|| parse_usize("limit", matches))
+            .transpose()?,
+        schedule: parse_schedule("schedule", matches)?,
+        timezone: parse_string("timezone", matches).ok(),
+        until: matches
+            .is_present("until")
+            .then(|| parse_i64("until", matches))
+            .transpose()?,
     })
 }
 
@@ -145,30 +186,113 @@ fn parse_pool_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
 
 fn parse_queue_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
     match matches.subcommand() {
+        Some(("accept", matches)) => Ok(CliCommand::QueueAccept {
+            creator: parse_pubkey("creator", matches)?,
+            id: parse_string("id", matches)?,
+        }),
         Some(("create", matches)) => Ok(CliCommand::QueueCreate {
             id: parse_string("id", matches)?,
-            kickoff_instruction: parse_instruction_file("kickoff_instruction", matches)?,
+            kickoff_instructions: parse_instructions_file("kickoff_instruction", matches)?,
             trigger: parse_trigger(matches)?,
         }),
         Some(("delete", matches)) => Ok(CliCommand::QueueDelete {
+            queues: parse_queue_selector(matches)?,
+        }),
+        Some(("fund", matches)) => Ok(CliCommand::QueueFund {
+            amount: parse_u64("amount", matches)?,
             id: parse_string("id", matches)?,
         }),
         Some(("get", matches)) => Ok(CliCommand::QueueGet {
             id: parse_string("id", matches)?,
         }),
-        Some(("pause", matches)) => Ok(CliCommand::QueuePause {
+        Some(("grant", matches)) => Ok(CliCommand::QueueGrant {
+            delegate: parse_pubkey("delegate", matches)?,
+            id: parse_string("id", matches)?,
+            role: parse_role("role", matches)?,
+        }),
+        Some(("history", matches)) => Ok(CliCommand::QueueHistory {
             id: parse_string("id", matches)?,
         }),
+        Some(("list", matches)) => Ok(CliCommand::QueueList {
+            authority: parse_pubkey("authority", matches).ok(),
+            paused: if matches.is_present("paused") {
+                Some(true)
+            } else if matches.is_present("active") {
+                Some(false)
+            } else {
+                None
+            },
+            program_id: parse_pubkey("program_id", matches).ok(),
+            trigger_type: matches
+                .is_present("trigger")
+                .then(|| parse_trigger_type("trigger", matches))
+                .transpose()?,
+        }),
+        Some(("migrate", matches)) => Ok(CliCommand::QueueMigrate {
+            id: parse_string("id", matches)?,
+        }),
+        Some(("pause", matches)) => Ok(CliCommand::QueuePause {
+            queues: parse_queue_selector(matches)?,
+        }),
         Some(("resume", matches)) => Ok(CliCommand::QueueResume {
+            queues: parse_queue_selector(matches)?,
+        }),
+        Some(("revoke", matches)) => Ok(CliCommand::QueueRevoke {
+            delegate: parse_pubkey("delegate", matches)?,
             id: parse_string("id", matches)?,
+            role: parse_role("role", matches)?,
         }),
         Some(("stop", matches)) => Ok(CliCommand::QueueStop {
+            queues: parse_queue_selector(matches)?,
+        }),
+        Some(("transfer", matches)) => Ok(CliCommand::QueueTransfer {
             id: parse_string("id", matches)?,
+            new_authority: parse_pubkey("new_authority", matches)?,
         }),
         Some(("update", matches)) => Ok(CliCommand::QueueUpdate {
+            address_lookup_tables: parse_pubkeys("address_lookup_tables", matches).ok(),
+            compute_unit_limit: matches
+                .is_present("compute_unit_limit")
+                .then(|| parse_u32("compute_unit_limit", matches))
+                .transpose()?,
+            compute_unit_price: matches
+                .is_present("compute_unit_price")
+                .then(|| parse_u64("compute_unit_price", matches))
+                .transpose()?,
+            end_at: matches
+                .is_present("end_at")
+                .then(|| parse_i64("end_at", matches))
+                .transpose()?,
+            exec_history_limit: matches
+                .is_present("exec_history_limit")
+                .then(|| parse_u64("exec_history_limit", matches))
+                .transpose()?,
             id: parse_string("id", matches)?,
-            rate_limit: parse_u64("rate_limit", matches).ok(),
-            schedule: parse_string("schedule", matches).ok(),
+            max_compute_unit_price: matches
+                .is_present("max_compute_unit_price")
+                .then(|| parse_u64("max_compute_unit_price", matches))
+                .transpose()?,
+            max_runs: matches
+                .is_present("max_runs")
+                .then(|| parse_u64("max_runs", matches))
+                .transpose()?,
+            min_balance: matches
+                .is_present("min_balance")
+                .then(|| parse_u64("min_balance", matches))
+                .transpose()?,
+            rate_limit: matches
+                .is_present("rate_limit")
+                .then(|| parse_u64("rate_limit", matches))
+                .transpose()?,
+            schedule: matches
+                .is_present("schedule")
+                .then(|| parse_schedule("schedule", matches))
+                .transpose()?,
+            start_at: matches
+                .is_present("start_at")
+                .then(|| parse_i64("start_at", matches))
+                .transpose()?,
+            timezone: parse_string("timezone", matches).ok(),
         }),
         _ => Err(CliError::CommandNotRecognized(
             matches.subcommand().unwrap().0.into(),
@@ -213,27 +337,200 @@ fn parse_worker_command(matches: &ArgMatches) -> Result<CliCommand, CliError> {
 
 fn parse_trigger(matches: &ArgMatches) -> Result<Trigger, CliError> {
     if matches.is_present("account") {
-        return Ok(Trigger::Account {
-            pubkey: parse_pubkey("address", matches)?,
+        // Watch the whole account, unless a byte range is given.
+        if !matches.is_present("size") {
+            if matches.is_present("offset") {
+                return Err(CliError::BadParameter("size".into()));
+            }
+            return Ok(Trigger::Account {
+                pubkey: parse_pubkey("account", matches)?,
+            });
+        }
+        return Ok(Trigger::AccountRange {
+            pubkey: parse_pubkey("account", matches)?,
+            offset: matches
+                .is_present("offset")
+                .then(|| parse_u64("offset", matches))
+                .transpose()?
+                .unwrap_or(0),
+            size: parse_u64("size", matches)?,
         });
+    } else if matches.is_present("condition") {
+        let operand = parse_string("operand", matches)?
+            .parse::<i128>()
+            .map_err(|_err| CliError::BadParameter("operand".into()))?;
+        return condition_trigger(
+            parse_pubkey("condition", matches)?,
+            matches
+                .is_present("offset")
+                .then(|| parse_u64("offset", matches))
+                .transpose()?
+                .unwrap_or(0),
+            parse_string("value_type", matches)?.as_str(),
+            parse_string("endianness", matches)
+                .unwrap_or_else(|_err| "little".into())
+                .as_str(),
+            parse_string("operator", matches)?.as_str(),
+            operand,
+        );
     } else if matches.is_present("cron") {
         return Ok(Trigger::Cron {
-            schedule: parse_string("cron", matches)?,
+            schedule: parse_schedule("cron", matches)?,
             skippable: true,
+            timezone: parse_string("timezone", matches).ok(),
+            start_at: matches
+                .is_present("start_at")
+                .then(|| parse_i64("start_at", matches))
+                .transpose()?,
+            end_at: matches
+                .is_present("end_at")
+                .then(|| parse_i64("end_at", matches))
+                .transpose()?,
+            max_runs: matches
+                .is_present("max_runs")
+                .then(|| parse_u64("max_runs", matches))
+                .transpose()?,
         });
     } else if matches.is_present("immediate") {
         return Ok(Trigger::Immediate);
+    } else if matches.is_present("slot") {
+        return Ok(Trigger::Slot {
+            slot: parse_u64("slot", matches)?,
+        });
+    } else if matches.is_present("epoch") {
+        return Ok(Trigger::Epoch {
+            epoch: parse_u64("epoch", matches)?,
+        });
+    } else if matches.is_present("after_queue") {
+        return Ok(Trigger::Queue {
+            pubkey: parse_pubkey("after_queue", matches)?,
+        });
+    } else if matches.is_present("interval") {
+        return Ok(Trigger::Interval {
+            seconds: parse_u64("interval", matches)?,
+            skippable: true,
+        });
+    } else if matches.is_present("timestamp") {
+        return Ok(Trigger::Timestamp {
+            unix_ts: parse_i64("timestamp", matches)?,
+        });
     }
 
     Err(CliError::BadParameter("trigger".into()))
 }
 
-fn parse_instruction_file(arg: &str, matches: &ArgMatches) -> Result<InstructionData, CliError> {
+// Builds a condition trigger from the names of its value type, endianness and operator.
+fn condition_trigger(
+    pubkey: Pubkey,
+    offset: u64,
+    value_type: &str,
+    endianness: &str,
+    operator: &str,
+    operand: i128,
+) -> Result<Trigger, CliError> {
+    let value_type = match value_type {
+        "u8" => ValueType::U8,
+        "u16" => ValueType::U16,
+        "u32" => ValueType::U32,
+        "u64" => ValueType::U64,
+        "u128" => ValueType::U128,
+        "i8" => ValueType::I8,
+        "i16" => ValueType::I16,
+        "i32" => ValueType::I32,
+        "i64" => ValueType::I64,
+        "i128" => ValueType::I128,
+        _ => return Err(CliError::BadParameter("value_type".into())),
+    };
+    let endianness = match endianness {
+        "little" => Endianness::Little,
+        "big" => Endianness::Big,
+        _ => return Err(CliError::BadParameter("endianness".into())),
+    };
+    let operator = match operator {
+        "eq" => Operator::Equal,
+        "ne" => Operator::NotEqual,
+        "gt" => Operator::GreaterThan,
+        "ge" => Operator::GreaterThanOrEqual,
+        "lt" => Operator::LessThan,
+        "le" => Operator::LessThanOrEqual,
+        _ => return Err(CliError::BadParameter("operator".into())),
+    };
+    Ok(Trigger::Condition {
+        pubkey,
+        offset,
+        value_type,
+        endianness,
+        operator,
+        operand,
+    })
+}
+
+fn parse_queue_selector(matches: &ArgMatches) -> Result<QueueSelector, CliError> {
+    if matches.is_present("match") {
+        return Ok(QueueSelector::Match(parse_string("match", matches)?));
+    }
+    if matches.is_present("file") {
+        return Ok(QueueSelector::Ids(parse_ids_file("file", matches)?));
+    }
+    Ok(QueueSelector::Id(parse_string("id", matches)?))
+}
+
+fn parse_ids_file(arg: &str, matches: &ArgMatches) -> Result<Vec<String>, CliError> {
     let filepath = parse_string(arg, matches)?;
     let text = fs::read_to_string(filepath).map_err(|_err| CliError::BadParameter(arg.into()))?;
-    let ix: JsonInstructionData =
-        serde_json::from_str(text.as_str()).expect("JSON was not well-formatted");
-    InstructionData::try_from(&ix)
+
+    // Ignore blank lines and comments.
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+fn parse_manifest_file(arg: &str, matches: &ArgMatches) -> Result<Vec<QueueManifest>, CliError> {
+    let filepath = parse_string(arg, matches)?;
+    let text = fs::read_to_string(&filepath).map_err(|_err| CliError::BadParameter(arg.into()))?;
+
+    // Parse JSON manifests by their extension, and everything else as YAML.
+    let manifest: JsonManifest = if filepath.ends_with(".json") {
+        serde_json::from_str(text.as_str())
+            .map_err(|err| CliError::InvalidManifest(err.to_string()))?
+    } else {
+        serde_yaml::from_str(text.as_str())
+            .map_err(|err| CliError::InvalidManifest(err.to_string()))?
+    };
+
+    let mut queues: Vec<QueueManifest> = vec![];
+    for queue in manifest.queues.iter() {
+        if queues.iter().any(|q| q.id.eq(&queue.id)) {
+            return Err(CliError::InvalidManifest(format!(
+                "Duplicate queue id: {}",
+                queue.id
+            )));
+        }
+        queues.push(QueueManifest::try_from(queue)?);
+    }
+    Ok(queues)
+}
+
+fn parse_instructions_file(
+    arg: &str,
+    matches: &ArgMatches,
+) -> Result<Vec<InstructionData>, CliError> {
+    let filepath = parse_string(arg, matches)?;
+    let text = fs::read_to_string(filepath).map_err(|_err| CliError::BadParameter(arg.into()))?;
+
+    // The file may describe either a single instruction or an ordered list of instructions.
+    let ixs: Vec<JsonInstructionData> = match serde_json::from_str(text.as_str()) {
+        Ok(ixs) => ixs,
+        Err(_err) => {
+            vec![serde_json::from_str(text.as_str()).expect("JSON was not well-formatted")]
+        }
+    };
+    ixs.iter()
+        .map(|ix| InstructionData::try_from(ix))
+        .collect::<Result<Vec<InstructionData>, CliError>>()
 }
 
 fn parse_keypair_file(arg: &str, matches: &ArgMatches) -> Result<Keypair, CliError> {
@@ -246,11 +543,49 @@ fn parse_http_method(arg: &str, matches: &ArgMatches) -> Result<HttpMethod, CliE
         .map_err(|_err| CliError::BadParameter(arg.into()))?)
 }
 
+fn parse_role(arg: &str, matches: &ArgMatches) -> Result<Role, CliError> {
+    match parse_string(arg, matches)?.as_str() {
+        "pauser" => Ok(Role::Pauser),
+        "updater" => Ok(Role::Updater),
+        "withdrawer" => Ok(Role::Withdrawer),
+        _ => Err(CliError::BadParameter(arg.into())),
+    }
+}
+
+fn parse_trigger_type(arg: &str, matches: &ArgMatches) -> Result<TriggerType, CliError> {
+    match parse_string(arg, matches)?.as_str() {
+        "account" => Ok(TriggerType::Account),
+        "condition" => Ok(TriggerType::Condition),
+        "cron" => Ok(TriggerType::Cron),
+        "epoch" => Ok(TriggerType::Epoch),
+        "immediate" => Ok(TriggerType::Immediate),
+        "interval" => Ok(TriggerType::Interval),
+        "queue" => Ok(TriggerType::Queue),
+        "slot" => Ok(TriggerType::Slot),
+        "timestamp" => Ok(TriggerType::Timestamp),
+        _ => Err(CliError::BadParameter(arg.into())),
+    }
+}
+
 fn parse_pubkey(arg: &str, matches: &ArgMatches) -> Result<Pubkey, CliError> {
     Ok(Pubkey::from_str(parse_string(arg, matches)?.as_str())
         .map_err(|_err| CliError::BadParameter(arg.into()))?)
 }
 
+fn parse_pubkeys(arg: &str, matches: &ArgMatches) -> Result<Vec<Pubkey>, CliError> {
+    matches
+        .values_of(arg)
+        .ok_or(CliError::BadParameter(arg.into()))?
+        .map(|value| Pubkey::from_str(value).map_err(|_err| CliError::BadParameter(arg.into())))
+        .collect()
+}
+
+fn parse_schedule(arg: &str, matches: &ArgMatches) -> Result<String, CliError> {
+    let schedule = parse_string(arg, matches)?;
+    Schedule::from_str(&schedule).map_err(|err| CliError::InvalidSchedule(err.to_string()))?;
+    Ok(schedule)
+}
+
 fn parse_string(arg: &str, matches: &ArgMatches) -> Result<String, CliError> {
     Ok(matches
         .value_of(arg)
@@ -258,18 +593,22 @@ fn parse_string(arg: &str, matches: &ArgMatches) -> Result<String, CliError> {
         .to_string())
 }
 
-pub fn _parse_i64(arg: &str, matches: &ArgMatches) -> Result<i64, CliError> {
-    Ok(parse_string(arg, matches)?
+pub fn parse_i64(arg: &str, matches: &ArgMatches) -> Result<i64, CliError> {
+    parse_string(arg, matches)?
         .parse::<i64>()
         .map_err(|_err| CliError::BadParameter(arg.into()))
-        .unwrap())
+}
+
+pub fn parse_u32(arg: &str, matches: &ArgMatches) -> Result<u32, CliError> {
+    parse_string(arg, matches)?
+        .parse::<u32>()
+        .map_err(|_err| CliError::BadParameter(arg.into()))
 }
 
 pub fn parse_u64(arg: &str, matches: &ArgMatches) -> Result<u64, CliError> {
-    Ok(parse_string(arg, matches)?
+    parse_string(arg, matches)?
         .parse::<u64>()
         .map_err(|_err| CliError::BadParameter(arg.into()))
-        .unwrap())
 }
 
 pub fn parse_usize(arg: &str, matches: &ArgMatches) -> Result<usize, CliError> {
@@ -333,6 +672,195 @@ impl TryFrom<&JsonAccountMetaData> for AccountMetaData {
     }
 }
 
+#[derive(Debug, JsonDeserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonManifest {
+    pub queues: Vec<JsonQueueManifest>,
+}
+
+#[derive(Debug, JsonDeserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonQueueManifest {
+    pub id: String,
+    // Triggers are written as a map from the trigger type to its parameters, in YAML and JSON alike.
+    #[serde(with = "serde_yaml::with::singleton_map")]
+    pub trigger: JsonTrigger,
+    pub kickoff_instructions: Vec<JsonInstructionData>,
+    pub fee: Option<u64>,
+    pub rate_limit: Option<u64>,
+    pub compute_unit_limit: Option<u32>,
+    pub compute_unit_price: Option<u64>,
+}
+
+#[derive(Debug, JsonDeserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum JsonTrigger {
+    Account {
+        pubkey: String,
+        offset: Option<u64>,
+        size: Option<u64>,
+    },
+    Condition {
+        pubkey: String,
+        #[serde(default)]
+        offset: u64,
+        value_type: String,
+        endianness: Option<String>,
+        operator: String,
+        operand: i128,
+    },
+    Cron {
+        schedule: String,
+        #[serde(default = "default_skippable")]
+        skippable: bool,
+        timezone: Option<String>,
+        start_at: Option<i64>,
+        end_at: Option<i64>,
+        max_runs: Option<u64>,
+    },
+    Epoch {
+        epoch: u64,
+    },
+    Immediate,
+    Interval {
+        seconds: u64,
+        #[serde(default = "default_skippable")]
+        skippable: bool,
+    },
+    Queue {
+        pubkey: String,
+    },
+    Slot {
+        slot: u64,
+    },
+    Timestamp {
+        unix_ts: i64,
+    },
+}
+
+// Triggers created from the command line are skippable, so manifests default to the same.
+fn default_skippable() -> bool {
+    true
+}
+
+impl TryFrom<&JsonTrigger> for Trigger {
+    type Error = CliError;
+
+    fn try_from(value: &JsonTrigger) -> Result<Self, Self::Error> {
+        let parse_pubkey = |pubkey: &String| {
+            Pubkey::from_str(pubkey.as_str())
+                .map_err(|_err| CliError::BadParameter("Could not parse pubkey".into()))
+        };
+        Ok(match value {
+            JsonTrigger::Account {
+                pubkey,
+                offset,
+                size,
+            } => match (offset, size) {
+                (None, None) => Trigger::Account {
+                    pubkey: parse_pubkey(pubkey)?,
+                },
+                (offset, Some(size)) => Trigger::AccountRange {
+                    pubkey: parse_pubkey(pubkey)?,
+                    offset: offset.unwrap_or(0),
+                    size: *size,
+                },
+                (Some(_), None) => {
+                    return Err(CliError::BadParameter(
+                        "Account triggers with an offset require a size".into(),
+                    ))
+                }
+            },
+            JsonTrigger::Condition {
+                pubkey,
+                offset,
+                value_type,
+                endianness,
+                operator,
+                operand,
+            } => condition_trigger(
+                parse_pubkey(pubkey)?,
+                *offset,
+                value_type,
+                endianness.as_deref().unwrap_or("little"),
+                operator,
+                *operand,
+            )?,
+            JsonTrigger::Cron {
+                schedule,
+                skippable,
+                timezone,
+                start_at,
+                end_at,
+                max_runs,
+            } => {
+                Schedule::from_str(schedule)
+                    .map_err(|err| CliError::InvalidSchedule(err.to_string()))?;
+                Trigger::Cron {
+                    schedule: schedule.clone(),
+                    skippable: *skippable,
+                    timezone: timezone.clone(),
+                    start_at: *start_at,
+                    end_at: *end_at,
+                    max_runs: *max_runs,
+                }
+            }
+            JsonTrigger::Epoch { epoch } => Trigger::Epoch { epoch: *epoch },
+            JsonTrigger::Immediate => Trigger::Immediate,
+            JsonTrigger::Interval { seconds, skippable } => Trigger::Interval {
+                seconds: *seconds,
+                skippable: *skippable,
+            },
+            JsonTrigger::Queue { pubkey } => Trigger::Queue {
+                pubkey: parse_pubkey(pubkey)?,
+            },
+            JsonTrigger::Slot { slot } => Trigger::Slot { slot: *slot },
+            JsonTrigger::Timestamp { unix_ts } => Trigger::Timestamp { unix_ts: *unix_ts },
+        })
+    }
+}
+
+/// The desired state of a queue, as described by a manifest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueueManifest {
+    pub id: String,
+    pub kickoff_instructions: Vec<InstructionData>,
+    pub trigger: Trigger,
+    // Settings omitted from the manifest take the values queues are created with.
+    pub fee: u64,
+    pub rate_limit: u64,
+    pub compute_unit_limit: u32,
+    pub compute_unit_price: u64,
+}
+
+impl TryFrom<&JsonQueueManifest> for QueueManifest {
+    type Error = CliError;
+
+    fn try_from(value: &JsonQueueManifest) -> Result<Self, Self::Error> {
+        if value.kickoff_instructions.is_empty() {
+            return Err(CliError::InvalidManifest(format!(
+                "Queue {} has no kickoff instructions",
+                value.id
+            )));
+        }
+        Ok(QueueManifest {
+            id: value.id.clone(),
+            kickoff_instructions: value
+                .kickoff_instructions
+                .iter()
+                .map(InstructionData::try_from)
+                .collect::<Result<Vec<InstructionData>, CliError>>()?,
+            trigger: Trigger::try_from(&value.trigger)?,
+            fee: value.fee.unwrap_or(MINIMUM_FEE),
+            rate_limit: value.rate_limit.unwrap_or(DEFAULT_RATE_LIMIT),
+            compute_unit_limit: value
+                .compute_unit_limit
+                .unwrap_or(DEFAULT_COMPUTE_UNIT_LIMIT),
+            compute_unit_price: value.compute_unit_price.unwrap_or(0),
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProgramInfo {
     pub program_id: Pubkey,
@@ -0,0 +1,256 @@
+use {
+    crate::{errors::CliError, parser::QueueManifest},
+    clockwork_client::{
+        queue::{
+            objects::{
+                Queue, QueueSettings, DEFAULT_COMPUTE_UNIT_LIMIT, DEFAULT_RATE_LIMIT, MINIMUM_FEE,
+            },
+            QueueInfo,
+        },
+        Client,
+    },
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey},
+};
+
+// The changes needed to bring one queue on-chain in line with the manifest.
+struct QueuePlan {
+    description: String,
+    id: String,
+    ixs: Vec<Instruction>,
+}
+
+pub fn apply(
+    client: &Client,
+    dry_run: bool,
+    prune: bool,
+    queues: Vec<QueueManifest>,
+) -> Result<(), CliError> {
+    let authority = client.payer_pubkey();
+    let existing = client
+        .queues_by_authority(&authority)
+        .map_err(|err| CliError::BadClient(err.to_string()))?;
+
+    // Plan the changes needed to bring the queues on-chain in line with the manifest.
+    // Every queue is validated before any transaction is sent.
+    let mut plans: Vec<QueuePlan> = vec![];
+    let mut errors: Vec<String> = vec![];
+    for manifest in queues.iter() {
+        match plan(client, authority, &existing, manifest) {
+            Ok(plan) => plans.push(plan),
+            Err(err) => errors.push(format!("! {}: {}", manifest.id, err)),
+        }
+    }
+
+    // Delete the queues which are not in the manifest, if asked to.
+    if prune {
+        for info in existing
+            .iter()
+            .filter(|info| !queues.iter().any(|q| q.id.eq(&info.queue.id)))
+        {
+            plans.push(QueuePlan {
+                description: format!("- delete {} ({})", info.queue.id, info.pubkey),
+                id: info.queue.id.clone(),
+                ixs: vec![clockwork_client::queue::instruction::queue_delete(
+                    authority,
+                    authority,
+                    info.pubkey,
+                )],
+            });
+        }
+    }
+
+    for plan in plans.iter() {
+        println!("{}", plan.description);
+    }
+    for error in errors.iter() {
+        println!("{}", error);
+    }
+    if !errors.is_empty() {
+        return Err(CliError::InvalidManifest(format!(
+            "{} queue(s) cannot be applied, so no changes were made",
+            errors.len()
+        )));
+    }
+    if dry_run {
+        println!("Dry run, no transactions were sent");
+        return Ok(());
+    }
+
+    // Apply the changes to each queue separately, so one failure does not stop the rest.
+    let plans: Vec<&QueuePlan> = plans.iter().filter(|plan| !plan.ixs.is_empty()).collect();
+    if plans.is_empty() {
+        println!("Nothing to apply");
+        return Ok(());
+    }
+    let mut failures = 0;
+    for plan in plans.iter() {
+        match client.send_and_confirm_batched(&plan.ixs, &[client.payer()]) {
+            Ok(signatures) => {
+                println!("Applied {} in {} transaction(s)", plan.id, signatures.len())
+            }
+            Err(err) => {
+                failures += 1;
+                println!("Failed to apply {}: {}", plan.id, err);
+            }
+        }
+    }
+    if failures > 0 {
+        return Err(CliError::FailedTransaction(format!(
+            "{} of {} queue(s) failed to apply",
+            failures,
+            plans.len()
+        )));
+    }
+    Ok(())
+}
+
+// Plan the changes to one queue, or explain why they cannot be applied.
+fn plan(
+    client: &Client,
+    authority: Pubkey,
+    existing: &[QueueInfo],
+    manifest: &QueueManifest,
+) -> Result<QueuePlan, String> {
+    // Prefer the queue the authority created, if it also holds a transferred queue with the same id.
+    let matches: Vec<&QueueInfo> = existing
+        .iter()
+        .filter(|info| info.queue.id.eq(&manifest.id))
+        .collect();
+    let info = match matches.as_slice() {
+        [] => None,
+        [info] => Some(*info),
+        _ => Some(
+            *matches
+                .iter()
+                .find(|info| info.queue.creator.eq(&authority))
+                .ok_or("multiple of your queues have this id")?,
+        ),
+    };
+
+    match info {
+        None => {
+            // A queue's address is derived from its creator, so a queue which was transferred away still holds it.
+            let queue_pubkey = Queue::pubkey(authority, manifest.id.clone());
+            if client.get_account(&queue_pubkey).is_ok() {
+                return Err(format!(
+                    "the queue's address ({}) is already taken, e.g. by a queue you have transferred away",
+                    queue_pubkey
+                ));
+            }
+
+            let mut ixs = vec![clockwork_client::queue::instruction::queue_create(
+                authority,
+                manifest.id.clone(),
+                manifest.kickoff_instructions.clone(),
+                authority,
+                queue_pubkey,
+                manifest.trigger.clone(),
+            )];
+
+            // Queues are created with the default settings, so set any others afterwards.
+            let settings = QueueSettings {
+                compute_unit_limit: Some(manifest.compute_unit_limit)
+                    .filter(|limit| limit.ne(&DEFAULT_COMPUTE_UNIT_LIMIT)),
+                compute_unit_price: Some(manifest.compute_unit_price).filter(|price| price.ne(&0)),
+                fee: Some(manifest.fee).filter(|fee| fee.ne(&MINIMUM_FEE)),
+                rate_limit: Some(manifest.rate_limit).filter(|limit| limit.ne(&DEFAULT_RATE_LIMIT)),
+                ..unchanged_settings()
+            };
+            if settings.compute_unit_limit.is_some()
+                || settings.compute_unit_price.is_some()
+                || settings.fee.is_some()
+                || settings.rate_limit.is_some()
+            {
+                ixs.push(clockwork_client::queue::instruction::queue_update(
+                    authority,
+                    queue_pubkey,
+                    settings,
+                ));
+            }
+
+            Ok(QueuePlan {
+                description: format!("+ create {} ({})", manifest.id, queue_pubkey),
+                id: manifest.id.clone(),
+                ixs,
+            })
+        }
+        Some(info) => {
+            let queue = &info.queue;
+            let settings = QueueSettings {
+                compute_unit_limit: Some(manifest.compute_unit_limit)
+                    .filter(|limit| limit.ne(&queue.compute_unit_limit)),
+                compute_unit_price: Some(manifest.compute_unit_price)
+                    .filter(|price| price.ne(&queue.compute_unit_price)),
+                fee: Some(manifest.fee).filter(|fee| fee.ne(&queue.fee)),
+                kickoff_instructions: Some(manifest.kickoff_instructions.clone())
+                    .filter(|ixs| ixs.ne(&queue.kickoff_instructions)),
+                rate_limit: Some(manifest.rate_limit).filter(|limit| limit.ne(&queue.rate_limit)),
+                trigger: Some(manifest.trigger.clone())
+                    .filter(|trigger| trigger.ne(&queue.trigger)),
+                ..unchanged_settings()
+            };
+
+            let changed: Vec<&str> = [
+                ("compute_unit_limit", settings.compute_unit_limit.is_some()),
+                ("compute_unit_price", settings.compute_unit_price.is_some()),
+                ("fee", settings.fee.is_some()),
+                (
+                    "kickoff_instructions",
+                    settings.kickoff_instructions.is_some(),
+                ),
+                ("rate_limit", settings.rate_limit.is_some()),
+                ("trigger", settings.trigger.is_some()),
+            ]
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .map(|(field, _)| field)
+            .collect();
+            if changed.is_empty() {
+                return Ok(QueuePlan {
+                    description: format!("  unchanged {} ({})", manifest.id, info.pubkey),
+                    id: manifest.id.clone(),
+                    ixs: vec![],
+                });
+            }
+
+            // The program rejects trigger changes while the queue is in the middle of a run.
+            if settings.trigger.is_some() && !queue.next_instructions.is_empty() {
+                return Err(
+                    "the queue is running, so its trigger cannot be changed until the run completes"
+                        .into(),
+                );
+            }
+
+            Ok(QueuePlan {
+                description: format!(
+                    "~ update {} ({}): {}",
+                    manifest.id,
+                    info.pubkey,
+                    changed.join(", ")
+                ),
+                id: manifest.id.clone(),
+                ixs: vec![clockwork_client::queue::instruction::queue_update(
+                    authority,
+                    info.pubkey,
+                    settings,
+                )],
+            })
+        }
+    }
+}
+
+// Settings which leave every property of the queue unchanged.
+fn unchanged_settings() -> QueueSettings {
+    QueueSettings {
+        address_lookup_tables: None,
+        compute_unit_limit: None,
+        compute_unit_price: None,
+        exec_history_limit: None,
+        fee: None,
+        kickoff_instructions: None,
+        max_compute_unit_price: None,
+        min_balance: None,
+        rate_limit: None,
+        trigger: None,
+    }
+}
@@ -1,25 +1,117 @@
 use {
     crate::errors::CliError,
-    chrono::{DateTime, NaiveDateTime, Utc},
+    chrono::{DateTime, NaiveDateTime, TimeZone, Utc},
     clockwork_client::Client,
-    clockwork_cron::Schedule,
-    std::str::FromStr,
+    clockwork_cron::{Schedule, Tz},
+    serde_json::json,
+    std::{fmt::Display, str::FromStr},
 };
 
-pub fn get(client: &Client, schedule: String) -> Result<(), CliError> {
-    let clock = client.get_clock().unwrap();
-    let schedule = Schedule::from_str(schedule.as_str()).unwrap();
-
-    let mut i = 0;
-    for t in schedule.after(&DateTime::<Utc>::from_utc(
-        NaiveDateTime::from_timestamp(clock.unix_timestamp, 0),
-        Utc,
-    )) {
-        println!("{:#?}", t);
-        i += 1;
-        if i > 8 {
-            break;
+/// The number of fire times to print if no window is given.
+const DEFAULT_LIMIT: usize = 9;
+
+#[allow(clippy::too_many_arguments)]
+pub fn get(
+    client: &Client,
+    count: bool,
+    diff: Option<String>,
+    from: Option<i64>,
+    json: bool,
+    limit: Option<usize>,
+    schedule: String,
+    timezone: Option<String>,
+    until: Option<i64>,
+) -> Result<(), CliError> {
+    let schedule = Schedule::from_str(schedule.as_str())
+        .map_err(|err| CliError::InvalidSchedule(err.to_string()))?;
+    let diff = diff
+        .map(|diff| Schedule::from_str(diff.as_str()))
+        .transpose()
+        .map_err(|err| CliError::InvalidSchedule(err.to_string()))?;
+
+    // Default to the cluster's current time if no start of the window is given.
+    let from = match from {
+        Some(from) => from,
+        None => {
+            client
+                .get_clock()
+                .map_err(|err| CliError::BadClient(err.to_string()))?
+                .unix_timestamp
+        }
+    };
+    let after = utc_datetime(from, "from")?;
+    let until = until
+        .map(|until| utc_datetime(until, "until"))
+        .transpose()?;
+
+    // Only cap the output of an unbounded window by default.
+    let table = Table {
+        schedule: &schedule,
+        diff: diff.as_ref(),
+        count,
+        json,
+        limit: match (limit, until) {
+            (None, None) => Some(DEFAULT_LIMIT),
+            (limit, _) => limit,
+        },
+    };
+
+    match timezone {
+        None => table.print(&after, until.as_ref()),
+        Some(timezone) => {
+            let timezone = Tz::from_str(timezone.as_str())
+                .map_err(|err| CliError::BadParameter(err.to_string()))?;
+            table.print(
+                &after.with_timezone(&timezone),
+                until.map(|until| until.with_timezone(&timezone)).as_ref(),
+            )
         }
     }
     Ok(())
 }
+
+fn utc_datetime(timestamp: i64, arg: &str) -> Result<DateTime<Utc>, CliError> {
+    NaiveDateTime::from_timestamp_opt(timestamp, 0)
+        .map(|datetime| Utc.from_utc_datetime(&datetime))
+        .ok_or_else(|| CliError::BadParameter(arg.into()))
+}
+
+struct Table<'a> {
+    schedule: &'a Schedule,
+    diff: Option<&'a Schedule>,
+    count: bool,
+    json: bool,
+    limit: Option<usize>,
+}
+
+impl Table<'_> {
+    fn print<Z: TimeZone>(&self, after: &DateTime<Z>, until: Option<&DateTime<Z>>)
+    where
+        Z::Offset: Display,
+    {
+        let times: Box<dyn Iterator<Item = DateTime<Z>> + '_> = match (until, self.diff) {
+            (Some(until), Some(diff)) => Box::new(self.schedule.difference(diff, after, until)),
+            (Some(until), None) => Box::new(self.schedule.between(after, until)),
+            (None, _) => Box::new(self.schedule.after(after)),
+        };
+
+        if self.count {
+            let count = times.count();
+            if self.json {
+                println!("{}", json!({ "count": count }));
+            } else {
+                println!("{}", count);
+            }
+        } else if self.json {
+            let times: Vec<_> = times
+                .take(self.limit.unwrap_or(usize::MAX))
+                .map(|t| json!({ "timestamp": t.timestamp(), "datetime": t.to_rfc3339() }))
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&times).unwrap());
+        } else {
+            for t in times.take(self.limit.unwrap_or(usize::MAX)) {
+                println!("{}", t.to_rfc3339());
+            }
+        }
+    }
+}
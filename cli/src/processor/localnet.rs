@@ -119,16 +119,22 @@ fn create_queues(client: &Client, mint_pubkey: Pubkey) -> Result<()> {
     let ix_a = clockwork_client::queue::instruction::queue_create(
         client.payer_pubkey(),
         epoch_queue_id.into(),
-        clockwork_client::network::instruction::registry_epoch_kickoff(
-            epoch_queue_pubkey,
-            Snapshot::pubkey(0),
-        )
-        .into(),
+        vec![
+            clockwork_client::network::instruction::registry_epoch_kickoff(
+                epoch_queue_pubkey,
+                Snapshot::pubkey(0),
+            )
+            .into(),
+        ],
         client.payer_pubkey(),
         epoch_queue_pubkey,
         Trigger::Cron {
             schedule: "0 * * * * * *".into(),
             skippable: true,
+            timezone: None,
+            start_at: None,
+            end_at: None,
+            max_runs: None,
         },
     );
 
@@ -138,12 +144,18 @@ fn create_queues(client: &Client, mint_pubkey: Pubkey) -> Result<()> {
     let ix_b = clockwork_client::queue::instruction::queue_create(
         client.payer_pubkey(),
         hasher_queue_id.into(),
-        clockwork_client::network::instruction::registry_nonce_hash(hasher_queue_pubkey).into(),
+        vec![
+            clockwork_client::network::instruction::registry_nonce_hash(hasher_queue_pubkey).into(),
+        ],
         client.payer_pubkey(),
         hasher_queue_pubkey,
         Trigger::Cron {
             schedule: "*/15 * * * * * *".into(),
             skippable: true,
+            timezone: None,
+            start_at: None,
+            end_at: None,
+            max_runs: None,
         },
     );
 
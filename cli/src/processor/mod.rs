@@ -1,4 +1,5 @@
 mod api;
+mod apply;
 mod config;
 mod crontab;
 mod delegation;
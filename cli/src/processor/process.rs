@@ -29,13 +29,29 @@ pub fn process(matches: &ArgMatches) -> Result<(), CliError> {
             ack_authority,
             base_url,
         } => super::api::api_new(&client, ack_authority, base_url),
+        CliCommand::Apply {
+            dry_run,
+            prune,
+            queues,
+        } => super::apply::apply(&client, dry_run, prune, queues),
         CliCommand::ConfigGet => super::config::get(&client),
         CliCommand::ConfigSet {
             admin,
             epoch_queue,
             hasher_queue,
         } => super::config::set(&client, admin, epoch_queue, hasher_queue),
-        CliCommand::Crontab { schedule } => super::crontab::get(&client, schedule),
+        CliCommand::Crontab {
+            count,
+            diff,
+            from,
+            json,
+            limit,
+            schedule,
+            timezone,
+            until,
+        } => super::crontab::get(
+            &client, count, diff, from, json, limit, schedule, timezone, until,
+        ),
         CliCommand::DelegationCreate { worker_id } => super::delegation::create(&client, worker_id),
         CliCommand::DelegationDeposit {
             amount,
@@ -51,21 +67,65 @@ pub fn process(matches: &ArgMatches) -> Result<(), CliError> {
         CliCommand::PoolGet { id } => super::pool::get(&client, id),
         CliCommand::PoolList {} => super::pool::list(&client),
         CliCommand::PoolUpdate { id, size } => super::pool::update(&client, id, size),
+        CliCommand::QueueAccept { creator, id } => super::queue::accept(&client, creator, id),
         CliCommand::QueueCreate {
             id,
-            kickoff_instruction,
+            kickoff_instructions,
             trigger,
-        } => super::queue::create(&client, id, kickoff_instruction, trigger),
-        CliCommand::QueueDelete { id } => super::queue::delete(&client, id),
+        } => super::queue::create(&client, id, kickoff_instructions, trigger),
+        CliCommand::QueueDelete { queues } => super::queue::delete(&client, queues),
+        CliCommand::QueueFund { amount, id } => super::queue::fund(&client, amount, id),
         CliCommand::QueueGet { id } => super::queue::get(&client, id),
-        CliCommand::QueuePause { id } => super::queue::pause(&client, id),
-        CliCommand::QueueResume { id } => super::queue::resume(&client, id),
-        CliCommand::QueueStop { id } => super::queue::stop(&client, id),
+        CliCommand::QueueGrant { delegate, id, role } => {
+            super::queue::grant(&client, delegate, id, role)
+        }
+        CliCommand::QueueHistory { id } => super::queue::history(&client, id),
+        CliCommand::QueueList {
+            authority,
+            paused,
+            program_id,
+            trigger_type,
+        } => super::queue::list(&client, authority, paused, program_id, trigger_type),
+        CliCommand::QueueMigrate { id } => super::queue::migrate(&client, id),
+        CliCommand::QueuePause { queues } => super::queue::pause(&client, queues),
+        CliCommand::QueueResume { queues } => super::queue::resume(&client, queues),
+        CliCommand::QueueRevoke { delegate, id, role } => {
+            super::queue::revoke(&client, delegate, id, role)
+        }
+        CliCommand::QueueStop { queues } => super::queue::stop(&client, queues),
+        CliCommand::QueueTransfer { id, new_authority } => {
+            super::queue::transfer(&client, id, new_authority)
+        }
         CliCommand::QueueUpdate {
+            address_lookup_tables,
+            compute_unit_limit,
+            compute_unit_price,
+            end_at,
+            exec_history_limit,
+            id,
+            max_compute_unit_price,
+            max_runs,
+            min_balance,
+            rate_limit,
+            schedule,
+            start_at,
+            timezone,
+        } => super::queue::update(
+            &client,
+            address_lookup_tables,
+            compute_unit_limit,
+            compute_unit_price,
+            end_at,
+            exec_history_limit,
             id,
+            max_compute_unit_price,
+            max_runs,
+            min_balance,
             rate_limit,
             schedule,
-        } => super::queue::update(&client, id, rate_limit, schedule),
+            start_at,
+            timezone,
+        ),
         CliCommand::RegistryGet => super::registry::get(&client),
         CliCommand::RegistryUnlock => super::registry::unlock(&client),
         CliCommand::WebhookRequestNew {
@@ -1,23 +1,48 @@
 use {
-    crate::errors::CliError,
+    crate::{cli::QueueSelector, errors::CliError},
+    chrono::{DateTime, NaiveDateTime, Utc},
     clockwork_client::{
-        queue::objects::{Queue, QueueSettings, Trigger},
+        queue::{
+            objects::{Delegate, Queue, QueueSettings, Role, Trigger, TriggerContext},
+            QueueFilter, QueueInfo, TriggerType,
+        },
         Client,
     },
+    clockwork_cron::{Schedule, Tz},
     clockwork_utils::InstructionData,
+    solana_sdk::{instruction::Instruction, pubkey::Pubkey},
+    std::str::FromStr,
 };
 
+pub fn accept(client: &Client, creator: Pubkey, id: String) -> Result<(), CliError> {
+    let queue_pubkey = Queue::pubkey(creator, id);
+    let ix = clockwork_client::queue::instruction::queue_accept_authority(
+        client.payer_pubkey(),
+        queue_pubkey,
+    );
+    client
+        .send_and_confirm(&[ix], &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
+
+    // The queue's address is derived from its creator, so look it up directly.
+    let queue = client
+        .get::<Queue>(&queue_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(queue_pubkey.to_string()))?;
+    println!("Address: {}\n{:#?}", queue_pubkey, queue);
+    Ok(())
+}
+
 pub fn create(
     client: &Client,
     id: String,
-    kickoff_instruction: InstructionData,
+    kickoff_instructions: Vec<InstructionData>,
     trigger: Trigger,
 ) -> Result<(), CliError> {
     let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id.clone());
     let ix = clockwork_client::queue::instruction::queue_create(
         client.payer_pubkey(),
         id.clone(),
-        kickoff_instruction,
+        kickoff_instructions,
         client.payer_pubkey(),
         queue_pubkey,
         trigger,
@@ -27,69 +52,294 @@ pub fn create(
     Ok(())
 }
 
-pub fn delete(client: &Client, id: String) -> Result<(), CliError> {
-    let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id);
-    let ix = clockwork_client::queue::instruction::queue_delete(
-        client.payer_pubkey(),
+pub fn delete(client: &Client, queues: QueueSelector) -> Result<(), CliError> {
+    let ixs: Vec<Instruction> = select(client, &queues)?
+        .into_iter()
+        .map(|queue_pubkey| {
+            clockwork_client::queue::instruction::queue_delete(
+                client.payer_pubkey(),
+                client.payer_pubkey(),
+                queue_pubkey,
+            )
+        })
+        .collect();
+    send_batched(client, &ixs, "Deleted")
+}
+
+pub fn fund(client: &Client, amount: u64, id: String) -> Result<(), CliError> {
+    let queue_pubkey = resolve(client, &id)?;
+    let ix = clockwork_client::queue::instruction::queue_fund(
+        amount,
         client.payer_pubkey(),
         queue_pubkey,
     );
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+    client
+        .send_and_confirm(&[ix], &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
+    get(client, id)?;
     Ok(())
 }
 
 pub fn get(client: &Client, id: String) -> Result<(), CliError> {
-    let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id);
+    let queue_pubkey = resolve(client, &id)?;
     let queue = client
         .get::<Queue>(&queue_pubkey)
         .map_err(|_err| CliError::AccountDataNotParsable(queue_pubkey.to_string()))?;
     println!("Address: {}\n{:#?}", queue_pubkey, queue);
+
+    // Describe the schedule of cron-triggered queues.
+    if let Trigger::Cron {
+        schedule, timezone, ..
+    } = queue.trigger
+    {
+        if let Ok(schedule) = Schedule::from_str(schedule.as_str()) {
+            match timezone {
+                None => println!("Schedule: {} (UTC)", schedule.describe()),
+                Some(timezone) => println!("Schedule: {} ({})", schedule.describe(), timezone),
+            }
+        }
+    }
     Ok(())
 }
 
-pub fn pause(client: &Client, id: String) -> Result<(), CliError> {
-    let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id.clone());
-    let ix = clockwork_client::queue::instruction::queue_pause(client.payer_pubkey(), queue_pubkey);
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+pub fn grant(client: &Client, delegate: Pubkey, id: String, role: Role) -> Result<(), CliError> {
+    let queue_pubkey = resolve(client, &id)?;
+    let ix = clockwork_client::queue::instruction::queue_delegate_grant(
+        client.payer_pubkey(),
+        queue_pubkey,
+        Delegate {
+            pubkey: delegate,
+            role,
+        },
+    );
+    client
+        .send_and_confirm(&[ix], &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
     get(client, id)?;
     Ok(())
 }
 
-pub fn resume(client: &Client, id: String) -> Result<(), CliError> {
+pub fn history(client: &Client, id: String) -> Result<(), CliError> {
+    let queue_pubkey = resolve(client, &id)?;
+    let exec_history = client
+        .get_queue_history(&queue_pubkey)
+        .map_err(|_err| CliError::AccountDataNotParsable(queue_pubkey.to_string()))?;
+    for exec_record in exec_history.iter().rev() {
+        println!("{:#?}", exec_record);
+    }
+    Ok(())
+}
+
+pub fn list(
+    client: &Client,
+    authority: Option<Pubkey>,
+    paused: Option<bool>,
+    program_id: Option<Pubkey>,
+    trigger_type: Option<TriggerType>,
+) -> Result<(), CliError> {
+    let authority = authority.unwrap_or_else(|| client.payer_pubkey());
+    let mut queues = client
+        .queues(&QueueFilter {
+            authority: Some(authority),
+            paused,
+            program_id,
+            trigger_type,
+        })
+        .map_err(|err| CliError::BadClient(err.to_string()))?;
+    queues.sort_by(|a, b| a.queue.id.cmp(&b.queue.id));
+
+    // Print a row per queue, with the id column as wide as the longest id.
+    let id_width = queues
+        .iter()
+        .map(|info| info.queue.id.len())
+        .max()
+        .unwrap_or(0)
+        .max("ID".len());
+    println!(
+        "{:<id_width$}  {:<44}  {:<10}  {:<6}  {:>14}  NEXT FIRE TIME",
+        "ID",
+        "PUBKEY",
+        "TRIGGER",
+        "PAUSED",
+        "BALANCE",
+        id_width = id_width
+    );
+    for info in queues {
+        println!(
+            "{:<id_width$}  {:<44}  {:<10}  {:<6}  {:>14}  {}",
+            info.queue.id,
+            info.pubkey.to_string(),
+            TriggerType::from(&info.queue.trigger).to_string(),
+            info.queue.paused,
+            info.lamports,
+            next_fire_time(&info.queue).unwrap_or_else(|| "-".into()),
+            id_width = id_width
+        );
+    }
+
+    // Queues in a legacy layout cannot be listed until they are migrated.
+    let legacy_queues = client
+        .legacy_queues(Some(authority))
+        .map_err(|err| CliError::BadClient(err.to_string()))?;
+    for (queue_pubkey, queue) in legacy_queues {
+        println!(
+            "Queue {} ({}) is in a legacy layout. Migrate it with `clockwork queue migrate {}`",
+            queue.id, queue_pubkey, queue.id
+        );
+    }
+    Ok(())
+}
+
+pub fn migrate(client: &Client, id: String) -> Result<(), CliError> {
+    // Queues in a legacy layout predate authority transfers, so their address is derived from the payer.
     let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id.clone());
     let ix =
-        clockwork_client::queue::instruction::queue_resume(client.payer_pubkey(), queue_pubkey);
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+        clockwork_client::queue::instruction::queue_migrate(client.payer_pubkey(), queue_pubkey);
+    client
+        .send_and_confirm(&[ix], &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
+    get(client, id)
+}
+
+pub fn pause(client: &Client, queues: QueueSelector) -> Result<(), CliError> {
+    let ixs: Vec<Instruction> = select(client, &queues)?
+        .into_iter()
+        .map(|queue_pubkey| {
+            clockwork_client::queue::instruction::queue_pause(client.payer_pubkey(), queue_pubkey)
+        })
+        .collect();
+    send_batched(client, &ixs, "Paused")?;
+    if let QueueSelector::Id(id) = queues {
+        get(client, id)?;
+    }
+    Ok(())
+}
+
+pub fn resume(client: &Client, queues: QueueSelector) -> Result<(), CliError> {
+    let ixs: Vec<Instruction> = select(client, &queues)?
+        .into_iter()
+        .map(|queue_pubkey| {
+            clockwork_client::queue::instruction::queue_resume(client.payer_pubkey(), queue_pubkey)
+        })
+        .collect();
+    send_batched(client, &ixs, "Resumed")?;
+    if let QueueSelector::Id(id) = queues {
+        get(client, id)?;
+    }
+    Ok(())
+}
+
+pub fn revoke(client: &Client, delegate: Pubkey, id: String, role: Role) -> Result<(), CliError> {
+    let queue_pubkey = resolve(client, &id)?;
+    let ix = clockwork_client::queue::instruction::queue_delegate_revoke(
+        client.payer_pubkey(),
+        queue_pubkey,
+        Delegate {
+            pubkey: delegate,
+            role,
+        },
+    );
+    client
+        .send_and_confirm(&[ix], &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
     get(client, id)?;
     Ok(())
 }
 
-pub fn stop(client: &Client, id: String) -> Result<(), CliError> {
-    let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id.clone());
-    let ix = clockwork_client::queue::instruction::queue_stop(client.payer_pubkey(), queue_pubkey);
-    client.send_and_confirm(&[ix], &[client.payer()]).unwrap();
+pub fn stop(client: &Client, queues: QueueSelector) -> Result<(), CliError> {
+    let ixs: Vec<Instruction> = select(client, &queues)?
+        .into_iter()
+        .map(|queue_pubkey| {
+            clockwork_client::queue::instruction::queue_stop(client.payer_pubkey(), queue_pubkey)
+        })
+        .collect();
+    send_batched(client, &ixs, "Stopped")?;
+    if let QueueSelector::Id(id) = queues {
+        get(client, id)?;
+    }
+    Ok(())
+}
+
+pub fn transfer(client: &Client, id: String, new_authority: Pubkey) -> Result<(), CliError> {
+    let queue_pubkey = resolve(client, &id)?;
+    let ix = clockwork_client::queue::instruction::queue_transfer_authority(
+        client.payer_pubkey(),
+        queue_pubkey,
+        new_authority,
+    );
+    client
+        .send_and_confirm(&[ix], &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
     get(client, id)?;
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn update(
     client: &Client,
+    address_lookup_tables: Option<Vec<Pubkey>>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+    end_at: Option<i64>,
+    exec_history_limit: Option<u64>,
     id: String,
+    max_compute_unit_price: Option<u64>,
+    max_runs: Option<u64>,
+    min_balance: Option<u64>,
     rate_limit: Option<u64>,
     schedule: Option<String>,
+    start_at: Option<i64>,
+    timezone: Option<String>,
 ) -> Result<(), CliError> {
-    let queue_pubkey = Queue::pubkey(client.payer_pubkey(), id.clone());
+    let queue_pubkey = resolve(client, &id)?;
     let trigger = if let Some(schedule) = schedule {
         Some(Trigger::Cron {
             schedule,
             skippable: true,
+            timezone,
+            start_at,
+            end_at,
+            max_runs,
         })
+    } else if start_at.is_some() || end_at.is_some() || max_runs.is_some() || timezone.is_some() {
+        // Without a new schedule, apply the bounds and time zone to the queue's existing cron trigger.
+        let queue = client
+            .get::<Queue>(&queue_pubkey)
+            .map_err(|_err| CliError::AccountDataNotParsable(queue_pubkey.to_string()))?;
+        match queue.trigger {
+            Trigger::Cron {
+                schedule,
+                skippable,
+                timezone: prior_timezone,
+                start_at: prior_start_at,
+                end_at: prior_end_at,
+                max_runs: prior_max_runs,
+            } => Some(Trigger::Cron {
+                schedule,
+                skippable,
+                timezone: timezone.or(prior_timezone),
+                start_at: start_at.or(prior_start_at),
+                end_at: end_at.or(prior_end_at),
+                max_runs: max_runs.or(prior_max_runs),
+            }),
+            _ => {
+                return Err(CliError::BadParameter(
+                    "start_at, end_at, max_runs and timezone only apply to cron triggers".into(),
+                ))
+            }
+        }
     } else {
         None
     };
     let settings = QueueSettings {
+        address_lookup_tables,
+        compute_unit_limit,
+        compute_unit_price,
+        exec_history_limit,
         fee: None,
-        kickoff_instruction: None,
+        kickoff_instructions: None,
+        max_compute_unit_price,
+        min_balance,
         rate_limit,
         trigger,
     };
@@ -102,3 +352,167 @@ pub fn update(
     get(client, id)?;
     Ok(())
 }
+
+// Resolve the selected queues to their addresses.
+fn select(client: &Client, queues: &QueueSelector) -> Result<Vec<Pubkey>, CliError> {
+    match queues {
+        QueueSelector::Id(id) => resolve_all(client, std::slice::from_ref(id)),
+        QueueSelector::Ids(ids) => resolve_all(client, ids),
+        QueueSelector::Match(pattern) => Ok(client
+            .queues_by_authority(&client.payer_pubkey())
+            .map_err(|err| CliError::BadClient(err.to_string()))?
+            .into_iter()
+            .filter(|info| glob_match(pattern, &info.queue.id))
+            .map(|info| info.pubkey)
+            .collect()),
+    }
+}
+
+// Resolve a queue id to its address.
+fn resolve(client: &Client, id: &str) -> Result<Pubkey, CliError> {
+    Ok(resolve_all(client, &[id.to_owned()])?[0])
+}
+
+// Resolve queue ids to their addresses. A queue's address is derived from its creator, which is no
+// longer its authority once the queue has been transferred, so the ids are looked up among the
+// queues the payer has authority over.
+fn resolve_all(client: &Client, ids: &[String]) -> Result<Vec<Pubkey>, CliError> {
+    let payer_pubkey = client.payer_pubkey();
+    let queues = client
+        .queues_by_authority(&payer_pubkey)
+        .map_err(|err| CliError::BadClient(err.to_string()))?;
+    ids.iter()
+        .map(|id| {
+            let matches: Vec<&QueueInfo> =
+                queues.iter().filter(|info| info.queue.id.eq(id)).collect();
+            match matches.as_slice() {
+                // Fall back to the payer's own queue, e.g. to look up one it has transferred away.
+                [] => Ok(Queue::pubkey(payer_pubkey, id.clone())),
+                [info] => Ok(info.pubkey),
+                _ => matches
+                    .iter()
+                    .find(|info| info.queue.creator.eq(&payer_pubkey))
+                    .map(|info| info.pubkey)
+                    .ok_or_else(|| {
+                        CliError::BadParameter(format!("multiple queues have the id {}", id))
+                    }),
+            }
+        })
+        .collect()
+}
+
+// The next moment a queue's trigger is expected to fire, if it can be known ahead of time.
+// Mirrors the threshold checks the program makes when kicking off the queue.
+fn next_fire_time(queue: &Queue) -> Option<String> {
+    let trigger_context = queue
+        .exec_context
+        .map(|exec_context| exec_context.trigger_context);
+    match &queue.trigger {
+        Trigger::Cron {
+            schedule,
+            timezone,
+            start_at,
+            end_at,
+            max_runs,
+            ..
+        } => {
+            let (reference_timestamp, runs) = match trigger_context {
+                Some(TriggerContext::Cron { started_at }) => (started_at, 0),
+                Some(TriggerContext::BoundedCron { started_at, runs }) => (started_at, runs),
+                _ => (queue.created_at.unix_timestamp, 0),
+            };
+            if matches!(max_runs, Some(max_runs) if runs >= *max_runs) {
+                return None;
+            }
+            let reference_timestamp = match start_at {
+                None => reference_timestamp,
+                Some(start_at) => reference_timestamp.max(start_at.saturating_sub(1)),
+            };
+            let schedule = Schedule::from_str(schedule).ok()?;
+            let after = DateTime::<Utc>::from_utc(
+                NaiveDateTime::from_timestamp(reference_timestamp, 0),
+                Utc,
+            );
+            let next_timestamp = match timezone {
+                None => schedule.next_after(&after)?.timestamp(),
+                Some(timezone) => {
+                    let timezone = Tz::from_str(timezone).ok()?;
+                    schedule
+                        .next_after(&after.with_timezone(&timezone))?
+                        .timestamp()
+                }
+            };
+            if matches!(end_at, Some(end_at) if next_timestamp > *end_at) {
+                return None;
+            }
+            Some(format_timestamp(next_timestamp))
+        }
+        Trigger::Epoch { epoch } => match trigger_context {
+            Some(TriggerContext::Epoch { started_at }) => Some(format!("epoch {}", started_at + 1)),
+            _ => Some(format!("epoch {}", epoch)),
+        },
+        Trigger::Interval { seconds, .. } => {
+            let reference_timestamp = match trigger_context {
+                Some(TriggerContext::Interval { started_at }) => started_at,
+                _ => queue.created_at.unix_timestamp,
+            };
+            Some(format_timestamp(
+                reference_timestamp.checked_add(*seconds as i64)?,
+            ))
+        }
+        Trigger::Slot { slot } => trigger_context.is_none().then(|| format!("slot {}", slot)),
+        Trigger::Timestamp { unix_ts } => trigger_context
+            .is_none()
+            .then(|| format_timestamp(*unix_ts)),
+        _ => None,
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(timestamp, 0), Utc).to_rfc3339()
+}
+
+// Send the instructions in as few transactions as possible.
+fn send_batched(client: &Client, ixs: &[Instruction], action: &str) -> Result<(), CliError> {
+    let signatures = client
+        .send_and_confirm_batched(ixs, &[client.payer()])
+        .map_err(|err| CliError::FailedTransaction(err.to_string()))?;
+    println!(
+        "{} {} queue(s) in {} transaction(s)",
+        action,
+        ixs.len(),
+        signatures.len()
+    );
+    Ok(())
+}
+
+// Whether or not the text matches a glob pattern, where `*` matches any run of characters
+// and `?` matches any single character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` absorb one more character and try again.
+                Some((star, start)) => {
+                    backtrack = Some((star, start + 1));
+                    p = star + 1;
+                    t = start + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -8,6 +8,7 @@ use solana_sdk::{
     commitment_config::CommitmentConfig,
     hash::Hash,
     instruction::Instruction,
+    packet::PACKET_DATA_SIZE,
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
@@ -106,6 +107,38 @@ impl Client {
         tx.sign(signers, self.latest_blockhash()?);
         Ok(self.send_and_confirm_transaction(&tx)?)
     }
+
+    /// Send and confirm the instructions in as few transactions as possible, packing each
+    /// transaction with as many instructions as fit, in order.
+    pub fn send_and_confirm_batched<T: Signers>(
+        &self,
+        ixs: &[Instruction],
+        signers: &T,
+    ) -> ClientResult<Vec<Signature>> {
+        let mut signatures = vec![];
+        for batch in self.batch(ixs) {
+            signatures.push(self.send_and_confirm(batch, signers)?);
+        }
+        Ok(signatures)
+    }
+
+    // Split the instructions into consecutive batches which each fit in a single transaction.
+    fn batch<'a>(&self, ixs: &'a [Instruction]) -> Vec<&'a [Instruction]> {
+        let mut batches = vec![];
+        let mut start = 0;
+        for end in 1..=ixs.len() {
+            let tx = Transaction::new_with_payer(&ixs[start..end], Some(&self.payer_pubkey()));
+            let tx_size = bincode::serialized_size(&tx).unwrap_or(u64::MAX) as usize;
+            if tx_size > PACKET_DATA_SIZE && end - start > 1 {
+                batches.push(&ixs[start..end - 1]);
+                start = end - 1;
+            }
+        }
+        if start < ixs.len() {
+            batches.push(&ixs[start..]);
+        }
+        batches
+    }
 }
 
 impl Debug for Client {
@@ -0,0 +1,86 @@
+use anchor_lang::{AnchorDeserialize, Discriminator};
+
+pub use clockwork_queue_program::events::*;
+
+/// A lifecycle event emitted by the queue program.
+#[derive(Clone, Debug)]
+pub enum QueueEvent {
+    AuthorityAccepted(QueueAuthorityAccepted),
+    AuthorityTransferStarted(QueueAuthorityTransferStarted),
+    AutoPaused(QueueAutoPaused),
+    Cranked(QueueCranked),
+    Created(QueueCreated),
+    DelegateGranted(QueueDelegateGranted),
+    DelegateRevoked(QueueDelegateRevoked),
+    Deleted(QueueDeleted),
+    KickedOff(QueueKickedOff),
+    Migrated(QueueMigrated),
+    Paused(QueuePaused),
+    Resumed(QueueResumed),
+    Stopped(QueueStopped),
+}
+
+impl QueueEvent {
+    /// Decode an event from its serialized bytes, prefixed by the event's 8 byte discriminator.
+    pub fn try_from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+        let (discriminator, data) = data.split_at(8);
+        decode(discriminator, data)
+            .map(QueueEvent::AuthorityAccepted)
+            .or_else(|| decode(discriminator, data).map(QueueEvent::AuthorityTransferStarted))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::AutoPaused))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Cranked))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Created))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::DelegateGranted))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::DelegateRevoked))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Deleted))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::KickedOff))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Migrated))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Paused))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Resumed))
+            .or_else(|| decode(discriminator, data).map(QueueEvent::Stopped))
+    }
+}
+
+fn decode<T: AnchorDeserialize + Discriminator>(discriminator: &[u8], data: &[u8]) -> Option<T> {
+    if discriminator.ne(&T::discriminator()) {
+        return None;
+    }
+    T::try_from_slice(data).ok()
+}
+
+/// Decode the queue program's events from the log messages of a transaction.
+///
+/// Only `Program data:` logs emitted while the queue program is the currently executing program are decoded,
+/// so events logged by other programs (including programs invoked by a queue) are ignored.
+pub fn parse_queue_events(logs: &[String]) -> Vec<QueueEvent> {
+    let program_id = clockwork_queue_program::ID.to_string();
+    let mut invocations: Vec<bool> = vec![];
+    let mut events = vec![];
+    for log in logs {
+        let mut words = log.split_whitespace();
+        if words.next().ne(&Some("Program")) {
+            continue;
+        }
+        match (words.next(), words.next()) {
+            (Some("data:"), Some(data)) => {
+                if invocations.last().eq(&Some(&true)) {
+                    if let Some(event) = base64::decode(data)
+                        .ok()
+                        .and_then(|data| QueueEvent::try_from_bytes(&data))
+                    {
+                        events.push(event);
+                    }
+                }
+            }
+            (Some(id), Some("invoke")) => invocations.push(id.eq(&program_id)),
+            (Some(_id), Some("success")) | (Some(_id), Some("failed:")) => {
+                invocations.pop();
+            }
+            _ => {}
+        }
+    }
+    events
+}
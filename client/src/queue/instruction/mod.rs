@@ -1,17 +1,29 @@
+mod queue_accept_authority;
 mod queue_crank;
 mod queue_create;
+mod queue_delegate_grant;
+mod queue_delegate_revoke;
 mod queue_delete;
+mod queue_fund;
 mod queue_kickoff;
+mod queue_migrate;
 mod queue_pause;
 mod queue_resume;
 mod queue_stop;
+mod queue_transfer_authority;
 mod queue_update;
 
+pub use queue_accept_authority::*;
 pub use queue_crank::*;
 pub use queue_create::*;
+pub use queue_delegate_grant::*;
+pub use queue_delegate_revoke::*;
 pub use queue_delete::*;
+pub use queue_fund::*;
 pub use queue_kickoff::*;
+pub use queue_migrate::*;
 pub use queue_pause::*;
 pub use queue_resume::*;
 pub use queue_stop::*;
+pub use queue_transfer_authority::*;
 pub use queue_update::*;
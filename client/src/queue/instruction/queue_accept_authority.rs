@@ -0,0 +1,18 @@
+use anchor_lang::{
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+    InstructionData,
+};
+
+pub fn queue_accept_authority(new_authority: Pubkey, queue: Pubkey) -> Instruction {
+    Instruction {
+        program_id: clockwork_queue_program::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(new_authority, true),
+            AccountMeta::new(queue, false),
+        ],
+        data: clockwork_queue_program::instruction::QueueAcceptAuthority {}.data(),
+    }
+}
@@ -3,6 +3,7 @@ use {
         solana_program::{
             instruction::{AccountMeta, Instruction},
             pubkey::Pubkey,
+            sysvar,
         },
         InstructionData,
     },
@@ -14,6 +15,7 @@ pub fn queue_crank(queue: Pubkey, signatory: Pubkey, worker: Pubkey) -> Instruct
         program_id: clockwork_queue_program::ID,
         accounts: vec![
             AccountMeta::new(Fee::pubkey(worker), false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
             AccountMeta::new(Penalty::pubkey(worker), false),
             AccountMeta::new_readonly(Pool::pubkey(0), false),
             AccountMeta::new(queue, false),
@@ -0,0 +1,22 @@
+use clockwork_queue_program::objects::Delegate;
+
+use anchor_lang::{
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_program,
+    },
+    InstructionData,
+};
+
+pub fn queue_delegate_grant(authority: Pubkey, queue: Pubkey, delegate: Delegate) -> Instruction {
+    Instruction {
+        program_id: clockwork_queue_program::ID,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(queue, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: clockwork_queue_program::instruction::QueueDelegateGrant { delegate }.data(),
+    }
+}
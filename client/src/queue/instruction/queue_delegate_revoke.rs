@@ -0,0 +1,20 @@
+use clockwork_queue_program::objects::Delegate;
+
+use anchor_lang::{
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+    InstructionData,
+};
+
+pub fn queue_delegate_revoke(authority: Pubkey, queue: Pubkey, delegate: Delegate) -> Instruction {
+    Instruction {
+        program_id: clockwork_queue_program::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(queue, false),
+        ],
+        data: clockwork_queue_program::instruction::QueueDelegateRevoke { delegate }.data(),
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::{
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_program,
+    },
+    InstructionData,
+};
+
+pub fn queue_fund(amount: u64, funder: Pubkey, queue: Pubkey) -> Instruction {
+    Instruction {
+        program_id: clockwork_queue_program::ID,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new(queue, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: clockwork_queue_program::instruction::QueueFund { amount }.data(),
+    }
+}
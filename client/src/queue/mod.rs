@@ -1,5 +1,10 @@
+pub mod events;
 pub mod instruction;
 
+mod queries;
+
+pub use queries::{QueueFilter, QueueInfo, TriggerType};
+
 pub use clockwork_queue_program::errors;
 pub use clockwork_queue_program::objects;
 pub use clockwork_queue_program::ID;
@@ -0,0 +1,188 @@
+use {
+    crate::{
+        queue::{objects::*, ID},
+        Client, ClientResult,
+    },
+    anchor_lang::{AccountDeserialize, AnchorDeserialize, Discriminator},
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
+    solana_sdk::{account::Account, pubkey::Pubkey},
+    std::fmt,
+};
+
+/// The offset of the authority field in a queue account, just after its discriminator.
+const AUTHORITY_OFFSET: usize = 8;
+
+/// A queue account, along with its address and lamport balance.
+#[derive(Debug)]
+pub struct QueueInfo {
+    pub pubkey: Pubkey,
+    pub queue: Queue,
+    pub lamports: u64,
+}
+
+/// The conditions a queue must satisfy to be included in a query.
+/// Only the authority is filtered by the RPC node, the rest are applied to the fetched queues.
+#[derive(Clone, Debug, Default)]
+pub struct QueueFilter {
+    /// Only include queues owned by this authority.
+    pub authority: Option<Pubkey>,
+    /// Only include queues which are paused, or only those which are not.
+    pub paused: Option<bool>,
+    /// Only include queues with an instruction targeting this program.
+    pub program_id: Option<Pubkey>,
+    /// Only include queues with this type of trigger.
+    pub trigger_type: Option<TriggerType>,
+}
+
+impl QueueFilter {
+    fn includes(&self, queue: &Queue) -> bool {
+        if let Some(paused) = self.paused {
+            if queue.paused.ne(&paused) {
+                return false;
+            }
+        }
+        if let Some(program_id) = self.program_id {
+            if !queue
+                .kickoff_instructions
+                .iter()
+                .chain(queue.next_instructions.iter())
+                .any(|ix| ix.program_id.eq(&program_id))
+            {
+                return false;
+            }
+        }
+        if let Some(trigger_type) = self.trigger_type {
+            if TriggerType::from(&queue.trigger).ne(&trigger_type) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// The type of a queue's trigger, without its parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriggerType {
+    Account,
+    Condition,
+    Cron,
+    Epoch,
+    Immediate,
+    Interval,
+    Queue,
+    Slot,
+    Timestamp,
+}
+
+impl From<&Trigger> for TriggerType {
+    fn from(trigger: &Trigger) -> Self {
+        match trigger {
+            Trigger::Account { .. } | Trigger::AccountRange { .. } => TriggerType::Account,
+            Trigger::Condition { .. } => TriggerType::Condition,
+            Trigger::Cron { .. } => TriggerType::Cron,
+            Trigger::Epoch { .. } => TriggerType::Epoch,
+            Trigger::Immediate => TriggerType::Immediate,
+            Trigger::Interval { .. } => TriggerType::Interval,
+            Trigger::Queue { .. } => TriggerType::Queue,
+            Trigger::Slot { .. } => TriggerType::Slot,
+            Trigger::Timestamp { .. } => TriggerType::Timestamp,
+        }
+    }
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TriggerType::Account => write!(f, "account"),
+            TriggerType::Condition => write!(f, "condition"),
+            TriggerType::Cron => write!(f, "cron"),
+            TriggerType::Epoch => write!(f, "epoch"),
+            TriggerType::Immediate => write!(f, "immediate"),
+            TriggerType::Interval => write!(f, "interval"),
+            TriggerType::Queue => write!(f, "queue"),
+            TriggerType::Slot => write!(f, "slot"),
+            TriggerType::Timestamp => write!(f, "timestamp"),
+        }
+    }
+}
+
+impl Client {
+    /// Fetch the execution history of a queue, ordered from oldest to newest.
+    pub fn get_queue_history(&self, queue_pubkey: &Pubkey) -> ClientResult<Vec<ExecRecord>> {
+        let queue = self.get::<Queue>(queue_pubkey)?;
+        Ok(queue.exec_history)
+    }
+
+    /// Fetch all queues satisfying a filter.
+    /// Accounts which cannot be read as a queue, such as queues in a legacy layout, are skipped.
+    pub fn queues(&self, filter: &QueueFilter) -> ClientResult<Vec<QueueInfo>> {
+        let mut queues = vec![];
+        for (pubkey, account) in self.queue_accounts(filter.authority)? {
+            let queue = match Queue::try_deserialize(&mut account.data.as_slice()) {
+                Err(_err) => continue,
+                Ok(queue) => queue,
+            };
+            if filter.includes(&queue) {
+                queues.push(QueueInfo {
+                    pubkey,
+                    queue,
+                    lamports: account.lamports,
+                });
+            }
+        }
+        Ok(queues)
+    }
+
+    /// Fetch all queues in a legacy layout which have yet to be migrated, optionally only those owned by an authority.
+    pub fn legacy_queues(&self, authority: Option<Pubkey>) -> ClientResult<Vec<(Pubkey, QueueV1)>> {
+        Ok(self
+            .queue_accounts(authority)?
+            .into_iter()
+            .filter(|(_pubkey, account)| {
+                Queue::try_deserialize(&mut account.data.as_slice()).is_err()
+            })
+            .filter_map(|(pubkey, account)| {
+                let queue = QueueV1::deserialize(&mut account.data.get(8..)?).ok()?;
+                Some((pubkey, queue))
+            })
+            .collect())
+    }
+
+    /// Fetch all accounts with the queue discriminator, optionally only those owned by an authority.
+    /// The authority is the first field of every queue layout, so legacy queues are included.
+    fn queue_accounts(&self, authority: Option<Pubkey>) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let mut filters = vec![RpcFilterType::Memcmp(Memcmp {
+            offset: 0,
+            bytes: MemcmpEncodedBytes::Bytes(Queue::discriminator().to_vec()),
+            encoding: None,
+        })];
+        if let Some(authority) = authority {
+            filters.push(RpcFilterType::Memcmp(Memcmp {
+                offset: AUTHORITY_OFFSET,
+                bytes: MemcmpEncodedBytes::Bytes(authority.to_bytes().to_vec()),
+                encoding: None,
+            }));
+        }
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        Ok(self.get_program_accounts_with_config(&ID, config)?)
+    }
+
+    /// Fetch all queues owned by an authority.
+    pub fn queues_by_authority(&self, authority: &Pubkey) -> ClientResult<Vec<QueueInfo>> {
+        self.queues(&QueueFilter {
+            authority: Some(*authority),
+            ..QueueFilter::default()
+        })
+    }
+}
@@ -0,0 +1,302 @@
+use crate::ordinal::{Ordinal, OrdinalSet};
+use crate::schedule::{Schedule, ScheduleFields};
+use crate::time_unit::*;
+
+impl Schedule {
+    /// Describes the schedule in plain English.
+    /// # Example
+    /// ```
+    /// use clockwork_cron::Schedule;
+    /// use std::str::FromStr;
+    ///
+    /// let schedule = Schedule::from_str("0 30 9 * May-Aug Mon,Wed,Fri").unwrap();
+    /// assert_eq!(
+    ///     schedule.describe(),
+    ///     "At 09:30 on Mon, Wed and Fri in May through August"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        describe(self.fields())
+    }
+}
+
+// The maximum number of times of day to list before describing the time fields separately.
+const MAX_LISTED_TIMES: usize = 6;
+
+const DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const NTH_NAMES: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+fn describe(fields: &ScheduleFields) -> String {
+    let mut description = describe_time(&fields.seconds, &fields.minutes, &fields.hours);
+    let mut clauses = [
+        describe_days(&fields.days_of_month, &fields.days_of_week),
+        describe_months(&fields.months),
+        describe_years(&fields.years),
+    ];
+
+    // Move a conditional days clause to the end, so it doesn't read as applying to the months or years.
+    if let Some(Some(days_clause)) = clauses.first() {
+        if days_clause.contains(", if ") {
+            clauses.rotate_left(1);
+        }
+    }
+
+    for clause in clauses.iter().flatten() {
+        description.push(' ');
+        description.push_str(clause);
+    }
+    capitalize(&description)
+}
+
+fn describe_time(seconds: &Seconds, minutes: &Minutes, hours: &Hours) -> String {
+    let (second_ordinals, minute_ordinals, hour_ordinals) =
+        (seconds.ordinals(), minutes.ordinals(), hours.ordinals());
+
+    // A handful of fixed times of day, e.g. "at 09:30 and 17:30".
+    if second_ordinals.len() == 1
+        && !minutes.is_all()
+        && !hours.is_all()
+        && minute_ordinals.len() * hour_ordinals.len() <= MAX_LISTED_TIMES
+    {
+        let second = *second_ordinals.iter().next().unwrap();
+        let times: Vec<String> = hour_ordinals
+            .iter()
+            .flat_map(|hour| {
+                minute_ordinals
+                    .iter()
+                    .map(move |minute| format_time(*hour, *minute, second))
+            })
+            .collect();
+        return format!("at {}", join(&times, "and"));
+    }
+
+    let second_clause = if seconds.is_all() {
+        Some("every second".to_owned())
+    } else if second_ordinals == single(0) {
+        None
+    } else {
+        Some(describe_unit(&second_ordinals, "second", 0, 59))
+    };
+    let minute_clause = if minutes.is_all() {
+        second_clause.is_none().then(|| "every minute".to_owned())
+    } else if minute_ordinals == single(0) && second_clause.is_none() {
+        None
+    } else {
+        Some(describe_unit(&minute_ordinals, "minute", 0, 59))
+    };
+    let hour_clause = if hours.is_all() {
+        (second_clause.is_none() && minute_clause.is_none()).then(|| "every hour".to_owned())
+    } else {
+        // A range of hours only reads as a span of time if every minute within it is included.
+        match runs(&hour_ordinals).as_slice() {
+            [(start, end)] if start < end && minutes.is_all() => Some(format!(
+                "between {} and {}",
+                format_time(*start, 0, 0),
+                format_time(*end, 59, 0)
+            )),
+            _ => Some(describe_unit(&hour_ordinals, "hour", 0, 23)),
+        }
+    };
+
+    let clauses: Vec<String> = vec![second_clause, minute_clause, hour_clause]
+        .into_iter()
+        .flatten()
+        .collect();
+    clauses.join(", ")
+}
+
+fn describe_days(days_of_month: &DaysOfMonth, days_of_week: &DaysOfWeek) -> Option<String> {
+    let mut month_days = vec![];
+    if !days_of_month.is_all() {
+        let ordinals = days_of_month.ordinals();
+        match ordinals.len() {
+            0 => {}
+            1 => month_days.push(format!("day {}", list_numbers(&ordinals))),
+            _ => month_days.push(format!("days {}", list_numbers(&ordinals))),
+        }
+        for rule in days_of_month.rules() {
+            month_days.push(match rule {
+                DayOfMonthRule::Last(0) => "the last day".to_owned(),
+                DayOfMonthRule::Last(1) => "the day before the last day".to_owned(),
+                DayOfMonthRule::Last(offset) => {
+                    format!("the day {} days before the last day", offset)
+                }
+                DayOfMonthRule::LastWeekday => "the last weekday".to_owned(),
+                DayOfMonthRule::NearestWeekday(day) => format!("the weekday nearest day {}", day),
+            });
+        }
+    }
+
+    // Days of the week narrow down the days of the month, if both are given.
+    let conjunction = if month_days.is_empty() { "and" } else { "or" };
+    let mut week_days = vec![];
+    if !days_of_week.is_all() {
+        let ordinals = days_of_week.ordinals();
+        if !ordinals.is_empty() {
+            week_days.push(list(&ordinals, conjunction, day_name));
+        }
+        for rule in days_of_week.rules() {
+            week_days.push(match rule {
+                DayOfWeekRule::Last(day) => format!("the last {} of the month", day_name(*day)),
+                DayOfWeekRule::Nth(day, n) => format!(
+                    "the {} {} of the month",
+                    NTH_NAMES[*n as usize - 1],
+                    day_name(*day)
+                ),
+            });
+        }
+    }
+
+    match (month_days.is_empty(), week_days.is_empty()) {
+        (true, true) => None,
+        (false, true) => Some(format!("on {} of the month", join(&month_days, "and"))),
+        (true, false) => Some(format!("on {}", join(&week_days, conjunction))),
+        (false, false) => Some(format!(
+            "on {} of the month, if it falls on {}",
+            join(&month_days, "and"),
+            join(&week_days, conjunction)
+        )),
+    }
+}
+
+fn describe_months(months: &Months) -> Option<String> {
+    if months.is_all() {
+        return None;
+    }
+    Some(format!(
+        "in {}",
+        list(&months.ordinals(), "and", month_name)
+    ))
+}
+
+fn describe_years(years: &Years) -> Option<String> {
+    if years.is_all() {
+        return None;
+    }
+    let ordinals = years.ordinals();
+    match step(&ordinals) {
+        Some(step) => Some(format!(
+            "every {} years from {} through {}",
+            step,
+            ordinals.iter().next().unwrap(),
+            ordinals.iter().next_back().unwrap()
+        )),
+        None => Some(format!("in {}", list_numbers(&ordinals))),
+    }
+}
+
+// Describes the ordinals of a unit of time, e.g. "every 15 minutes" or "at minutes 0 and 30".
+fn describe_unit(ordinals: &OrdinalSet, unit: &str, min: Ordinal, max: Ordinal) -> String {
+    let first = *ordinals.iter().next().unwrap();
+    let last = *ordinals.iter().next_back().unwrap();
+    match step(ordinals) {
+        Some(step) if first == min && last + step > max => format!("every {} {}s", step, unit),
+        Some(step) => format!(
+            "every {} {}s from {} {} through {}",
+            step, unit, unit, first, last
+        ),
+        None if ordinals.len() == 1 => format!("at {} {}", unit, first),
+        None => format!("at {}s {}", unit, list_numbers(ordinals)),
+    }
+}
+
+// Lists the ordinals by name, collapsing consecutive ordinals into ranges.
+fn list<F>(ordinals: &OrdinalSet, conjunction: &str, name: F) -> String
+where
+    F: Fn(Ordinal) -> String,
+{
+    let mut items = vec![];
+    for (start, end) in runs(ordinals) {
+        match end - start {
+            0 => items.push(name(start)),
+            1 => {
+                items.push(name(start));
+                items.push(name(end));
+            }
+            _ => items.push(format!("{} through {}", name(start), name(end))),
+        }
+    }
+    join(&items, conjunction)
+}
+
+fn list_numbers(ordinals: &OrdinalSet) -> String {
+    list(ordinals, "and", |ordinal| ordinal.to_string())
+}
+
+// Groups the ordinals into runs of consecutive ordinals.
+fn runs(ordinals: &OrdinalSet) -> Vec<(Ordinal, Ordinal)> {
+    let mut runs: Vec<(Ordinal, Ordinal)> = vec![];
+    for ordinal in ordinals.iter().cloned() {
+        match runs.last_mut() {
+            Some((_, end)) if *end + 1 == ordinal => *end = ordinal,
+            _ => runs.push((ordinal, ordinal)),
+        }
+    }
+    runs
+}
+
+// The step between the ordinals, if there are at least three of them and they're evenly spaced.
+fn step(ordinals: &OrdinalSet) -> Option<Ordinal> {
+    if ordinals.len() < 3 {
+        return None;
+    }
+    let ordinals: Vec<Ordinal> = ordinals.iter().cloned().collect();
+    let step = ordinals[1] - ordinals[0];
+    if step > 1 && ordinals.windows(2).all(|pair| pair[1] - pair[0] == step) {
+        Some(step)
+    } else {
+        None
+    }
+}
+
+fn join(items: &[String], conjunction: &str) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [init @ .., last] => format!("{} {} {}", init.join(", "), conjunction, last),
+    }
+}
+
+fn single(ordinal: Ordinal) -> OrdinalSet {
+    std::iter::once(ordinal).collect()
+}
+
+fn day_name(ordinal: Ordinal) -> String {
+    DAY_NAMES[ordinal as usize - 1].to_owned()
+}
+
+fn month_name(ordinal: Ordinal) -> String {
+    MONTH_NAMES[ordinal as usize - 1].to_owned()
+}
+
+fn format_time(hour: Ordinal, minute: Ordinal, second: Ordinal) -> String {
+    if second == 0 {
+        format!("{:02}:{:02}", hour, minute)
+    } else {
+        format!("{:02}:{:02}:{:02}", hour, minute, second)
+    }
+}
+
+fn capitalize(description: &str) -> String {
+    let mut chars = description.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+    }
+}
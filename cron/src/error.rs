@@ -5,15 +5,107 @@ pub struct Error {
     kind: ErrorKind,
 }
 
-#[derive(Debug)]
+impl Error {
+    /// The kind of error which occurred.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ErrorKind {
+    /// The expression as a whole is malformed (e.g. it has the wrong number of fields).
     Expression(String),
+    /// One of the expression's fields is invalid.
+    Field {
+        /// The field which failed to parse.
+        field: TimeUnit,
+        /// The byte offset in the expression at which the problem was found.
+        offset: usize,
+        /// Why the field is invalid.
+        reason: Reason,
+    },
+    UnknownTimeZone(String),
+}
+
+/// The fields of a cron expression, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    DaysOfMonth,
+    Months,
+    DaysOfWeek,
+    Years,
+}
+
+/// The reason a field of a cron expression is invalid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// An ordinal outside of the range supported by the field.
+    OutOfRange { ordinal: u32, min: u32, max: u32 },
+    /// A name which the field does not recognize.
+    UnknownName(String),
+    /// A range whose start comes after its end.
+    InvalidRange { start: String, end: String },
+    /// A period with a step of zero.
+    InvalidStep(u32),
+    /// A specifier which the field does not support, such as names in the hours field.
+    Unsupported(String),
+    /// Characters which could not be parsed.
+    Syntax(String),
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TimeUnit::Seconds => "seconds",
+            TimeUnit::Minutes => "minutes",
+            TimeUnit::Hours => "hours",
+            TimeUnit::DaysOfMonth => "days of month",
+            TimeUnit::Months => "months",
+            TimeUnit::DaysOfWeek => "days of week",
+            TimeUnit::Years => "years",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::OutOfRange { ordinal, min, max } => {
+                write!(f, "{} is out of range ({}-{})", ordinal, min, max)
+            }
+            Reason::UnknownName(name) => write!(f, "'{}' is not a valid name", name),
+            Reason::InvalidRange { start, end } => {
+                write!(f, "the range '{}-{}' ends before it starts", start, end)
+            }
+            Reason::InvalidStep(step) => write!(f, "'{}' is not a valid step", step),
+            Reason::Unsupported(specifier) => {
+                write!(f, "'{}' is not supported in this field", specifier)
+            }
+            Reason::Syntax(found) if found.is_empty() => write!(f, "unexpected end of field"),
+            Reason::Syntax(found) => write!(f, "unexpected '{}'", found),
+        }
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.kind {
             ErrorKind::Expression(ref expr) => write!(f, "Invalid expression: {}", expr),
+            ErrorKind::Field {
+                field,
+                offset,
+                ref reason,
+            } => write!(f, "Invalid {} field at byte {}: {}", field, offset, reason),
+            ErrorKind::UnknownTimeZone(ref name) => write!(
+                f,
+                "Unsupported time zone: {} (only a fixed set of common IANA zones is supported)",
+                name
+            ),
         }
     }
 }
@@ -40,6 +40,7 @@
 //! */
 //! ```
 
+mod description;
 pub mod error;
 mod ordinal;
 mod parsing;
@@ -47,6 +48,8 @@ mod queries;
 mod schedule;
 mod specifier;
 mod time_unit;
+mod tz;
 
 pub use crate::schedule::Schedule;
 pub use crate::time_unit::TimeUnitSpec;
+pub use crate::tz::{Tz, TzOffset};
@@ -1,19 +1,19 @@
 use nom::branch::alt;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{alpha1, digit1, multispace0};
 use nom::combinator::{all_consuming, eof, map, map_res, opt};
 use nom::multi::separated_list1;
-use nom::sequence::{delimited, separated_pair, terminated, tuple};
+use nom::sequence::{delimited, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
 
 use std::convert::TryFrom;
 use std::str::{self, FromStr};
 
-use crate::error::{Error, ErrorKind};
-use crate::schedule::{ScheduleFields, Schedule};
+use crate::error::{Error, ErrorKind, Reason, TimeUnit};
+use crate::ordinal::*;
+use crate::schedule::{Schedule, ScheduleFields};
 use crate::specifier::*;
 use crate::time_unit::*;
-use crate::ordinal::*;
 
 impl FromStr for Schedule {
     type Err = Error;
@@ -22,10 +22,103 @@ impl FromStr for Schedule {
             Ok((_, schedule_fields)) => {
                 Ok(Schedule::new(String::from(expression), schedule_fields))
             } // Extract from nom tuple
-            Err(_) => Err(ErrorKind::Expression("Invalid cron expression.".to_owned()).into()), //TODO: Details
+            Err(_) => Err(diagnose(expression).into()),
         }
     }
 }
+
+// Locates the first invalid field of an expression which failed to parse, by parsing each of its
+// fields in isolation.
+fn diagnose(expression: &str) -> ErrorKind {
+    if expression.trim_start().starts_with('@') {
+        return ErrorKind::Expression(format!("'{}' is not a valid shorthand.", expression.trim()));
+    }
+    let fields = split_fields(expression);
+    if fields.len() < 6 || fields.len() > 7 {
+        return ErrorKind::Expression(format!("Expected 6 or 7 fields, found {}.", fields.len()));
+    }
+    for (time_unit, (offset, input)) in TIME_UNITS.iter().zip(fields) {
+        let result = match time_unit {
+            TimeUnit::Seconds => check_field(input, field, Seconds::from_field),
+            TimeUnit::Minutes => check_field(input, field, Minutes::from_field),
+            TimeUnit::Hours => check_field(input, field, Hours::from_field),
+            TimeUnit::DaysOfMonth => {
+                check_field(input, days_of_month_field, days_of_month_from_field)
+            }
+            TimeUnit::Months => check_field(input, field, Months::from_field),
+            TimeUnit::DaysOfWeek => check_field(input, days_of_week_field, days_of_week_from_field),
+            TimeUnit::Years => check_field(input, field, Years::from_field),
+        };
+        if let Err((position, reason)) = result {
+            return ErrorKind::Field {
+                field: *time_unit,
+                offset: offset + position,
+                reason,
+            };
+        }
+    }
+    ErrorKind::Expression("Invalid cron expression.".to_owned())
+}
+
+const TIME_UNITS: [TimeUnit; 7] = [
+    TimeUnit::Seconds,
+    TimeUnit::Minutes,
+    TimeUnit::Hours,
+    TimeUnit::DaysOfMonth,
+    TimeUnit::Months,
+    TimeUnit::DaysOfWeek,
+    TimeUnit::Years,
+];
+
+// Splits an expression on whitespace, returning each field with its byte offset.
+fn split_fields(expression: &str) -> Vec<(usize, &str)> {
+    let mut fields = vec![];
+    let mut start = None;
+    for (offset, c) in expression.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(field_start)) => {
+                fields.push((field_start, &expression[field_start..offset]));
+                start = None;
+            }
+            (false, None) => start = Some(offset),
+            _ => {}
+        }
+    }
+    if let Some(field_start) = start {
+        fields.push((field_start, &expression[field_start..]));
+    }
+    fields
+}
+
+// Returns the position within the field and reason of the first problem with it.
+fn check_field<T>(
+    input: &str,
+    parser: fn(&str) -> IResult<&str, Field>,
+    convert: fn(Field) -> Result<T, Reason>,
+) -> Result<(), (usize, Reason)> {
+    let (_, field) = all_consuming(parser)(input).map_err(|err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => (
+            input.len() - err.input.len(),
+            Reason::Syntax(err.input.to_owned()),
+        ),
+        nom::Err::Incomplete(_) => (input.len(), Reason::Syntax(String::new())),
+    })?;
+    convert(field).map(|_| ()).map_err(|reason| {
+        // Check each of the field's specifiers in isolation to locate the one at fault.
+        let mut offset = 0;
+        for specifier in input.split(',') {
+            let is_invalid = match all_consuming(parser)(specifier) {
+                Ok((_, field)) => convert(field).is_err(),
+                Err(_) => false,
+            };
+            if is_invalid {
+                return (offset, reason);
+            }
+            offset += specifier.len() + 1;
+        }
+        (0, reason)
+    })
+}
 impl TryFrom<&str> for Schedule {
     type Error = Error;
 
@@ -44,18 +137,20 @@ where
     Self: Sized,
 {
     //TODO: Replace with std::convert::TryFrom when stable
-    fn from_field(field: Field) -> Result<Self, Error>;
+    fn from_field(field: Field) -> Result<Self, Reason>;
 }
 
 impl<T> FromField for T
 where
     T: TimeUnitField,
 {
-    fn from_field(field: Field) -> Result<T, Error> {
-        if field.specifiers.len() == 1 && 
-            field.specifiers.get(0).unwrap() == &RootSpecifier::from(Specifier::All) 
-            { return Ok(T::all()); }
-        let mut ordinals = OrdinalSet::new(); 
+    fn from_field(field: Field) -> Result<T, Reason> {
+        if field.specifiers.len() == 1
+            && field.specifiers.first() == Some(&RootSpecifier::from(Specifier::All))
+        {
+            return Ok(T::all());
+        }
+        let mut ordinals = OrdinalSet::new();
         for specifier in field.specifiers {
             let specifier_ordinals: OrdinalSet = T::ordinals_from_root_specifier(&specifier)?;
             for ordinal in specifier_ordinals {
@@ -66,6 +161,69 @@ where
     }
 }
 
+fn days_of_month_from_field(field: Field) -> Result<DaysOfMonth, Reason> {
+    let mut rules = vec![];
+    let mut specifiers = vec![];
+    for specifier in field.specifiers {
+        match specifier {
+            RootSpecifier::Last(offset) if offset < DaysOfMonth::inclusive_max() => {
+                rules.push(DayOfMonthRule::Last(offset))
+            }
+            RootSpecifier::Last(offset) => {
+                return Err(Reason::OutOfRange {
+                    ordinal: offset,
+                    min: 0,
+                    max: DaysOfMonth::inclusive_max() - 1,
+                })
+            }
+            RootSpecifier::LastWeekday => rules.push(DayOfMonthRule::LastWeekday),
+            RootSpecifier::NearestWeekday(ordinal) => rules.push(DayOfMonthRule::NearestWeekday(
+                DaysOfMonth::validate_ordinal(ordinal)?,
+            )),
+            specifier => specifiers.push(specifier),
+        }
+    }
+    let days_of_month = if specifiers.is_empty() {
+        DaysOfMonth::from_ordinal_set(OrdinalSet::new())
+    } else {
+        DaysOfMonth::from_field(Field { specifiers })?
+    };
+    Ok(days_of_month.with_rules(rules))
+}
+
+fn days_of_week_from_field(field: Field) -> Result<DaysOfWeek, Reason> {
+    let mut rules = vec![];
+    let mut specifiers = vec![];
+    for specifier in field.specifiers {
+        match specifier {
+            // A lone 'L' in the days of the week is the last day of the week.
+            RootSpecifier::Last(0) => {
+                specifiers.push(Specifier::Point(DaysOfWeek::inclusive_max()).into())
+            }
+            RootSpecifier::LastDayOfWeek(ordinal) => {
+                rules.push(DayOfWeekRule::Last(DaysOfWeek::validate_ordinal(ordinal)?))
+            }
+            RootSpecifier::NthDayOfWeek(ordinal, n) if (1..=5).contains(&n) => rules.push(
+                DayOfWeekRule::Nth(DaysOfWeek::validate_ordinal(ordinal)?, n),
+            ),
+            RootSpecifier::NthDayOfWeek(_, n) => {
+                return Err(Reason::OutOfRange {
+                    ordinal: n,
+                    min: 1,
+                    max: 5,
+                })
+            }
+            specifier => specifiers.push(specifier),
+        }
+    }
+    let days_of_week = if specifiers.is_empty() {
+        DaysOfWeek::from_ordinal_set(OrdinalSet::new())
+    } else {
+        DaysOfWeek::from_field(Field { specifiers })?
+    };
+    Ok(days_of_week.with_rules(rules))
+}
+
 fn ordinal(i: &str) -> IResult<&str, u32> {
     map_res(delimited(multispace0, digit1, multispace0), u32::from_str)(i)
 }
@@ -77,6 +235,14 @@ fn name(i: &str) -> IResult<&str, String> {
     )(i)
 }
 
+fn number(i: &str) -> IResult<&str, u32> {
+    map_res(digit1, u32::from_str)(i)
+}
+
+fn day_of_week(i: &str) -> IResult<&str, Ordinal> {
+    alt((number, map_res(alpha1, DaysOfWeek::ordinal_from_name)))(i)
+}
+
 fn point(i: &str) -> IResult<&str, Specifier> {
     let (i, o) = ordinal(i)?;
     Ok((i, Specifier::Point(o)))
@@ -119,6 +285,45 @@ fn all(i: &str) -> IResult<&str, Specifier> {
     Ok((i, Specifier::All))
 }
 
+fn last(i: &str) -> IResult<&str, RootSpecifier> {
+    map(
+        preceded(tag_no_case("L"), opt(preceded(tag("-"), number))),
+        |offset| RootSpecifier::Last(offset.unwrap_or(0)),
+    )(i)
+}
+
+fn last_weekday(i: &str) -> IResult<&str, RootSpecifier> {
+    let (i, _) = tag_no_case("LW")(i)?;
+    Ok((i, RootSpecifier::LastWeekday))
+}
+
+fn nearest_weekday(i: &str) -> IResult<&str, RootSpecifier> {
+    map(
+        terminated(number, tag_no_case("W")),
+        RootSpecifier::NearestWeekday,
+    )(i)
+}
+
+fn last_day_of_week(i: &str) -> IResult<&str, RootSpecifier> {
+    // Names are followed by an 'L' without a separator (e.g. 'FRIL').
+    let named = map_res(alpha1, |name: &str| {
+        match name.strip_suffix(|c| c == 'L' || c == 'l') {
+            Some(name) if !name.is_empty() => DaysOfWeek::ordinal_from_name(name),
+            _ => Err(Reason::UnknownName(name.to_owned())),
+        }
+    });
+    map(
+        alt((terminated(number, tag_no_case("L")), named)),
+        RootSpecifier::LastDayOfWeek,
+    )(i)
+}
+
+fn nth_day_of_week(i: &str) -> IResult<&str, RootSpecifier> {
+    map(separated_pair(day_of_week, tag("#"), number), |(day, n)| {
+        RootSpecifier::NthDayOfWeek(day, n)
+    })(i)
+}
+
 fn any(i: &str) -> IResult<&str, Specifier> {
     let (i, _) = tag("?")(i)?;
     Ok((i, Specifier::All))
@@ -144,15 +349,37 @@ fn root_specifier_with_any(i: &str) -> IResult<&str, RootSpecifier> {
     ))(i)
 }
 
+fn day_of_month_root_specifier(i: &str) -> IResult<&str, RootSpecifier> {
+    let special = alt((last_weekday, last, nearest_weekday));
+    alt((
+        delimited(multispace0, special, multispace0),
+        root_specifier_with_any,
+    ))(i)
+}
+
+fn day_of_week_root_specifier(i: &str) -> IResult<&str, RootSpecifier> {
+    let special = alt((last_day_of_week, nth_day_of_week, last));
+    alt((
+        delimited(multispace0, special, multispace0),
+        root_specifier_with_any,
+    ))(i)
+}
+
 fn root_specifier_list(i: &str) -> IResult<&str, Vec<RootSpecifier>> {
     let list = separated_list1(tag(","), root_specifier);
     let single_item = map(root_specifier, |spec| vec![spec]);
     delimited(multispace0, alt((list, single_item)), multispace0)(i)
 }
 
-fn root_specifier_list_with_any(i: &str) -> IResult<&str, Vec<RootSpecifier>> {
-    let list = separated_list1(tag(","), root_specifier_with_any);
-    let single_item = map(root_specifier_with_any, |spec| vec![spec]);
+fn day_of_month_root_specifier_list(i: &str) -> IResult<&str, Vec<RootSpecifier>> {
+    let list = separated_list1(tag(","), day_of_month_root_specifier);
+    let single_item = map(day_of_month_root_specifier, |spec| vec![spec]);
+    delimited(multispace0, alt((list, single_item)), multispace0)(i)
+}
+
+fn day_of_week_root_specifier_list(i: &str) -> IResult<&str, Vec<RootSpecifier>> {
+    let list = separated_list1(tag(","), day_of_week_root_specifier);
+    let single_item = map(day_of_week_root_specifier, |spec| vec![spec]);
     delimited(multispace0, alt((list, single_item)), multispace0)(i)
 }
 
@@ -161,8 +388,13 @@ fn field(i: &str) -> IResult<&str, Field> {
     Ok((i, Field { specifiers }))
 }
 
-fn field_with_any(i: &str) -> IResult<&str, Field> {
-    let (i, specifiers) = root_specifier_list_with_any(i)?;
+fn days_of_month_field(i: &str) -> IResult<&str, Field> {
+    let (i, specifiers) = day_of_month_root_specifier_list(i)?;
+    Ok((i, Field { specifiers }))
+}
+
+fn days_of_week_field(i: &str) -> IResult<&str, Field> {
+    let (i, specifiers) = day_of_week_root_specifier_list(i)?;
     Ok((i, Field { specifiers }))
 }
 
@@ -251,11 +483,19 @@ fn longhand(i: &str) -> IResult<&str, ScheduleFields> {
     let seconds = map_res(field, Seconds::from_field);
     let minutes = map_res(field, Minutes::from_field);
     let hours = map_res(field, Hours::from_field);
-    let days_of_month = map_res(field_with_any, DaysOfMonth::from_field);
+    let days_of_month = map_res(days_of_month_field, days_of_month_from_field);
     let months = map_res(field, Months::from_field);
-    let days_of_week = map_res(field_with_any, DaysOfWeek::from_field);
+    let days_of_week = map_res(days_of_week_field, days_of_week_from_field);
     let years = opt(map_res(field, Years::from_field));
-    let fields = tuple((seconds, minutes, hours, days_of_month, months, days_of_week, years));
+    let fields = tuple((
+        seconds,
+        minutes,
+        hours,
+        days_of_month,
+        months,
+        days_of_week,
+        years,
+    ));
 
     map(
         terminated(fields, eof),
@@ -322,20 +562,23 @@ mod test {
     fn test_nom_valid_number_list() {
         let expression = "1,2";
         field(expression).unwrap();
-        field_with_any(expression).unwrap();
+        days_of_month_field(expression).unwrap();
+        days_of_week_field(expression).unwrap();
     }
 
     #[test]
     fn test_nom_invalid_number_list() {
         let expression = ",1,2";
         assert!(field(expression).is_err());
-        assert!(field_with_any(expression).is_err());
+        assert!(days_of_month_field(expression).is_err());
+        assert!(days_of_week_field(expression).is_err());
     }
 
     #[test]
     fn test_nom_field_with_any_valid_any() {
         let expression = "?";
-        field_with_any(expression).unwrap();
+        days_of_month_field(expression).unwrap();
+        days_of_week_field(expression).unwrap();
     }
 
     #[test]
@@ -596,6 +839,76 @@ mod test {
         assert!(schedule(expression).is_err());
     }
 
+    #[test]
+    fn test_nom_valid_days_of_month_last() {
+        assert_eq!(
+            days_of_month_field("L").unwrap().1.specifiers,
+            vec![RootSpecifier::Last(0)]
+        );
+        assert_eq!(
+            days_of_month_field("L-3").unwrap().1.specifiers,
+            vec![RootSpecifier::Last(3)]
+        );
+        assert_eq!(
+            days_of_month_field("LW").unwrap().1.specifiers,
+            vec![RootSpecifier::LastWeekday]
+        );
+        assert_eq!(
+            days_of_month_field("1,15W,L").unwrap().1.specifiers,
+            vec![
+                RootSpecifier::from(Specifier::Point(1)),
+                RootSpecifier::NearestWeekday(15),
+                RootSpecifier::Last(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nom_valid_days_of_week_last_and_nth() {
+        assert_eq!(
+            days_of_week_field("FRIL").unwrap().1.specifiers,
+            vec![RootSpecifier::LastDayOfWeek(6)]
+        );
+        assert_eq!(
+            days_of_week_field("6L").unwrap().1.specifiers,
+            vec![RootSpecifier::LastDayOfWeek(6)]
+        );
+        assert_eq!(
+            days_of_week_field("FRI#3").unwrap().1.specifiers,
+            vec![RootSpecifier::NthDayOfWeek(6, 3)]
+        );
+        assert_eq!(
+            days_of_week_field("2#1,MON-WED").unwrap().1.specifiers,
+            vec![
+                RootSpecifier::NthDayOfWeek(2, 1),
+                RootSpecifier::from(Specifier::NamedRange("MON".into(), "WED".into()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nom_valid_extended_schedule() {
+        schedule("0 0 12 L * ?").unwrap();
+        schedule("0 0 12 LW * ?").unwrap();
+        schedule("0 0 12 15W * ?").unwrap();
+        schedule("0 0 12 ? * FRI#3").unwrap();
+        schedule("0 0 12 ? * 6L").unwrap();
+        schedule("0 0 12 ? * L").unwrap();
+    }
+
+    #[test]
+    fn test_nom_invalid_extended_schedule() {
+        // The L, W and # specifiers are limited to the days of the month and week.
+        assert!(schedule("0 0 L * * ?").is_err());
+        assert!(schedule("0 0 12 FRI#3 * ?").is_err());
+        assert!(schedule("0 0 12 ? * 15W").is_err());
+        assert!(schedule("0 0 12 ? * L-3").is_err());
+        assert!(schedule("0 0 12 ? * FRI#6").is_err());
+        assert!(schedule("0 0 12 32W * ?").is_err());
+        assert!(schedule("0 0 12 L-31 * ?").is_err());
+        assert!(schedule("0 0 12 L/2 * ?").is_err());
+    }
+
     /// Issue #86
     #[test]
     fn shorthand_must_match_whole_input() {
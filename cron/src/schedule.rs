@@ -1,5 +1,5 @@
-use chrono::offset::TimeZone;
-use chrono::{DateTime, Datelike, Timelike};
+use chrono::offset::{LocalResult, Offset, TimeZone};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::ops::Bound::{Included, Unbounded};
 
@@ -24,11 +24,36 @@ impl Schedule {
         Schedule { source, fields }
     }
 
+    pub(crate) fn fields(&self) -> &ScheduleFields {
+        &self.fields
+    }
+
+    // The length of the DST transition which repeats the local time of the given datetime, if the
+    // schedule fires during both occurrences of a repeated local time.
+    fn repeated_duration<Z: TimeZone>(&self, datetime: &DateTime<Z>) -> Duration {
+        if !self.fields.hours.is_all() {
+            return Duration::zero();
+        }
+        match datetime
+            .timezone()
+            .from_local_datetime(&datetime.naive_local())
+        {
+            LocalResult::Ambiguous(earliest, latest) => Duration::seconds(
+                (earliest.offset().fix().local_minus_utc()
+                    - latest.offset().fix().local_minus_utc()) as i64,
+            ),
+            _ => Duration::zero(),
+        }
+    }
+
     pub fn next_after<Z>(&self, after: &DateTime<Z>) -> Option<DateTime<Z>>
     where
         Z: TimeZone,
     {
-        let mut query = NextAfterQuery::from(after);
+        // If `after` is the first occurrence of a local time repeated by a DST transition, the
+        // second occurrences of earlier local times are still ahead of it. Like cron, only
+        // schedules which run every hour fire again during the repeated hour.
+        let mut query = NextAfterQuery::from(&(after.clone() - self.repeated_duration(after)));
         for year in self
             .fields
             .years
@@ -42,25 +67,15 @@ impl Schedule {
             }
             let month_range = (Included(month_start), Included(Months::inclusive_max()));
             for month in self.fields.months.ordinals().range(month_range).cloned() {
+                let days_of_month = self.fields.days_of_month.ordinals_in(month, year);
                 let day_of_month_start = query.day_of_month_lower_bound();
-                if !self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .contains(&day_of_month_start)
-                {
+                if !days_of_month.contains(&day_of_month_start) {
                     query.reset_day_of_month();
                 }
                 let day_of_month_end = days_in_month(month, year);
                 let day_of_month_range = (Included(day_of_month_start), Included(day_of_month_end));
 
-                'day_loop: for day_of_month in self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .range(day_of_month_range)
-                    .cloned()
-                {
+                'day_loop: for day_of_month in days_of_month.range(day_of_month_range).cloned() {
                     let hour_start = query.hour_lower_bound();
                     if !self.fields.hours.ordinals().contains(&hour_start) {
                         query.reset_hour();
@@ -86,24 +101,21 @@ impl Schedule {
                             for second in
                                 self.fields.seconds.ordinals().range(second_range).cloned()
                             {
-                                let timezone = after.timezone();
-                                let candidate = if let Some(candidate) = timezone
-                                    .ymd(year as i32, month, day_of_month)
-                                    .and_hms_opt(hour, minute, second)
+                                let local = if let Some(local) =
+                                    NaiveDate::from_ymd_opt(year as i32, month, day_of_month)
+                                        .and_then(|date| date.and_hms_opt(hour, minute, second))
                                 {
-                                    candidate
+                                    local
                                 } else {
                                     continue;
                                 };
-                                if !self
-                                    .fields
-                                    .days_of_week
-                                    .ordinals()
-                                    .contains(&candidate.weekday().number_from_sunday())
-                                {
+                                if !self.fields.days_of_week.matches(&local.date()) {
                                     continue 'day_loop;
                                 }
-                                return Some(candidate);
+                                // Local times skipped by a DST transition never occur.
+                                if let Some(candidate) = resolve_after(&local, after) {
+                                    return Some(candidate);
+                                }
                             }
                             query.reset_minute();
                         } // End of minutes range
@@ -123,7 +135,9 @@ impl Schedule {
     where
         Z: TimeZone,
     {
-        let mut query = PrevFromQuery::from(before);
+        // If `before` is the second occurrence of a local time repeated by a DST transition, the
+        // first occurrences of later local times are still behind it.
+        let mut query = PrevFromQuery::from(&(before.clone() + self.repeated_duration(before)));
         for year in self
             .fields
             .years
@@ -147,13 +161,9 @@ impl Schedule {
                 .rev()
                 .cloned()
             {
+                let days_of_month = self.fields.days_of_month.ordinals_in(month, year);
                 let day_of_month_end = query.day_of_month_upper_bound();
-                if !self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .contains(&day_of_month_end)
-                {
+                if !days_of_month.contains(&day_of_month_end) {
                     query.reset_day_of_month();
                 }
 
@@ -164,13 +174,8 @@ impl Schedule {
                     Included(day_of_month_end),
                 );
 
-                'day_loop: for day_of_month in self
-                    .fields
-                    .days_of_month
-                    .ordinals()
-                    .range(day_of_month_range)
-                    .rev()
-                    .cloned()
+                'day_loop: for day_of_month in
+                    days_of_month.range(day_of_month_range).rev().cloned()
                 {
                     let hour_start = query.hour_upper_bound();
                     if !self.fields.hours.ordinals().contains(&hour_start) {
@@ -216,24 +221,21 @@ impl Schedule {
                                 .rev()
                                 .cloned()
                             {
-                                let timezone = before.timezone();
-                                let candidate = if let Some(candidate) = timezone
-                                    .ymd(year as i32, month, day_of_month)
-                                    .and_hms_opt(hour, minute, second)
+                                let local = if let Some(local) =
+                                    NaiveDate::from_ymd_opt(year as i32, month, day_of_month)
+                                        .and_then(|date| date.and_hms_opt(hour, minute, second))
                                 {
-                                    candidate
+                                    local
                                 } else {
                                     continue;
                                 };
-                                if !self
-                                    .fields
-                                    .days_of_week
-                                    .ordinals()
-                                    .contains(&candidate.weekday().number_from_sunday())
-                                {
+                                if !self.fields.days_of_week.matches(&local.date()) {
                                     continue 'day_loop;
                                 }
-                                return Some(candidate);
+                                // Local times skipped by a DST transition never occur.
+                                if let Some(candidate) = resolve_before(&local, before) {
+                                    return Some(candidate);
+                                }
                             }
                             query.reset_minute();
                         } // End of minutes range
@@ -266,6 +268,37 @@ impl Schedule {
         ScheduleIterator::new(self, after)
     }
 
+    /// Like the `after` method, but stops after the last DateTime which is at or before `until`.
+    pub fn between<Z>(&self, after: &DateTime<Z>, until: &DateTime<Z>) -> ScheduleIterator<'_, Z>
+    where
+        Z: TimeZone,
+    {
+        ScheduleIterator::new(self, after).until(until)
+    }
+
+    /// Counts the DateTimes that match the schedule after `after` and at or before `until`.
+    pub fn count_between<Z>(&self, after: &DateTime<Z>, until: &DateTime<Z>) -> usize
+    where
+        Z: TimeZone,
+    {
+        self.between(after, until).count()
+    }
+
+    /// Provides an iterator which will return each DateTime after `after` and at or before `until`
+    /// that matches this schedule but not the `other` schedule.
+    pub fn difference<'a, Z>(
+        &'a self,
+        other: &'a Schedule,
+        after: &DateTime<Z>,
+        until: &DateTime<Z>,
+    ) -> impl Iterator<Item = DateTime<Z>> + 'a
+    where
+        Z: TimeZone + 'a,
+    {
+        self.between(after, until)
+            .filter(move |date_time| !other.includes(date_time.clone()))
+    }
+
     pub fn includes<Z>(&self, date_time: DateTime<Z>) -> bool
     where
         Z: TimeZone,
@@ -275,11 +308,12 @@ impl Schedule {
             && self
                 .fields
                 .days_of_week
-                .includes(date_time.weekday().number_from_sunday())
+                .matches(&date_time.naive_local().date())
             && self
                 .fields
                 .days_of_month
-                .includes(date_time.day() as Ordinal)
+                .ordinals_in(date_time.month() as Ordinal, date_time.year() as Ordinal)
+                .contains(&(date_time.day() as Ordinal))
             && self.fields.hours.includes(date_time.hour() as Ordinal)
             && self.fields.minutes.includes(date_time.minute() as Ordinal)
             && self.fields.seconds.includes(date_time.second() as Ordinal)
@@ -346,13 +380,13 @@ impl PartialEq for Schedule {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ScheduleFields {
-    years: Years,
-    days_of_week: DaysOfWeek,
-    months: Months,
-    days_of_month: DaysOfMonth,
-    hours: Hours,
-    minutes: Minutes,
-    seconds: Seconds,
+    pub(crate) years: Years,
+    pub(crate) days_of_week: DaysOfWeek,
+    pub(crate) months: Months,
+    pub(crate) days_of_month: DaysOfMonth,
+    pub(crate) hours: Hours,
+    pub(crate) minutes: Minutes,
+    pub(crate) seconds: Seconds,
 }
 
 impl ScheduleFields {
@@ -384,8 +418,8 @@ where
     is_done: bool,
     schedule: &'a Schedule,
     previous_datetime: DateTime<Z>,
+    cutoff_datetime: Option<DateTime<Z>>,
 }
-//TODO: Cutoff datetime?
 
 impl<'a, Z> ScheduleIterator<'a, Z>
 where
@@ -396,8 +430,15 @@ where
             is_done: false,
             schedule,
             previous_datetime: starting_datetime.clone(),
+            cutoff_datetime: None,
         }
     }
+
+    /// Stops the iterator after the last DateTime which is at or before the given cutoff.
+    pub fn until(mut self, cutoff_datetime: &DateTime<Z>) -> ScheduleIterator<'a, Z> {
+        self.cutoff_datetime = Some(cutoff_datetime.clone());
+        self
+    }
 }
 
 impl<'a, Z> Iterator for ScheduleIterator<'a, Z>
@@ -410,12 +451,22 @@ where
         if self.is_done {
             return None;
         }
-        if let Some(next_datetime) = self.schedule.next_after(&self.previous_datetime) {
-            self.previous_datetime = next_datetime.clone();
-            Some(next_datetime)
-        } else {
-            self.is_done = true;
-            None
+        match (
+            self.schedule.next_after(&self.previous_datetime),
+            &self.cutoff_datetime,
+        ) {
+            (Some(next_datetime), Some(cutoff_datetime)) if next_datetime > *cutoff_datetime => {
+                self.is_done = true;
+                None
+            }
+            (Some(next_datetime), _) => {
+                self.previous_datetime = next_datetime.clone();
+                Some(next_datetime)
+            }
+            (None, _) => {
+                self.is_done = true;
+                None
+            }
         }
     }
 }
@@ -439,6 +490,30 @@ where
     }
 }
 
+// Resolves a local time to its earliest occurrence strictly after the given datetime.
+fn resolve_after<Z: TimeZone>(local: &NaiveDateTime, after: &DateTime<Z>) -> Option<DateTime<Z>> {
+    match after.timezone().from_local_datetime(local) {
+        LocalResult::None => None,
+        LocalResult::Single(candidate) => Some(candidate),
+        LocalResult::Ambiguous(earliest, latest) => {
+            Some(if earliest > *after { earliest } else { latest })
+        }
+    }
+    .filter(|candidate| candidate > after)
+}
+
+// Resolves a local time to its latest occurrence strictly before the given datetime.
+fn resolve_before<Z: TimeZone>(local: &NaiveDateTime, before: &DateTime<Z>) -> Option<DateTime<Z>> {
+    match before.timezone().from_local_datetime(local) {
+        LocalResult::None => None,
+        LocalResult::Single(candidate) => Some(candidate),
+        LocalResult::Ambiguous(earliest, latest) => {
+            Some(if latest < *before { latest } else { earliest })
+        }
+    }
+    .filter(|candidate| candidate < before)
+}
+
 fn is_leap_year(year: Ordinal) -> bool {
     let by_four = year % 4 == 0;
     let by_hundred = year % 100 == 0;
@@ -446,7 +521,7 @@ fn is_leap_year(year: Ordinal) -> bool {
     by_four && ((!by_hundred) || by_four_hundred)
 }
 
-fn days_in_month(month: Ordinal, year: Ordinal) -> u32 {
+pub(crate) fn days_in_month(month: Ordinal, year: Ordinal) -> u32 {
     let is_leap_year = is_leap_year(year);
     match month {
         9 | 4 | 6 | 11 => 30,
@@ -1,4 +1,5 @@
 use crate::ordinal::*;
+use std::fmt;
 
 #[derive(Debug, PartialEq)]
 pub enum Specifier {
@@ -15,15 +16,53 @@ pub enum Specifier {
 // - named range: 'Mon-Thurs/2'
 //
 // Without this separation we would end up with invalid combinations such as 'Mon/2'
+//
+// Days of the month and week also support specifiers whose ordinals depend on the month:
+// - last: 'L', 'L-3'
+// - last weekday: 'LW'
+// - nearest weekday: '15W'
+// - last day of week: '5L', 'FRIL'
+// - nth day of week: '6#3', 'FRI#3'
 #[derive(Debug, PartialEq)]
 pub enum RootSpecifier {
     Specifier(Specifier),
     Period(Specifier, u32),
     NamedPoint(String),
+    Last(u32),
+    LastWeekday,
+    NearestWeekday(Ordinal),
+    LastDayOfWeek(Ordinal),
+    NthDayOfWeek(Ordinal, u32),
 }
 
 impl From<Specifier> for RootSpecifier {
     fn from(specifier: Specifier) -> Self {
         Self::Specifier(specifier)
     }
-}
\ No newline at end of file
+}
+impl fmt::Display for Specifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Specifier::All => write!(f, "*"),
+            Specifier::Point(ordinal) => write!(f, "{}", ordinal),
+            Specifier::Range(start, end) => write!(f, "{}-{}", start, end),
+            Specifier::NamedRange(start, end) => write!(f, "{}-{}", start, end),
+        }
+    }
+}
+
+impl fmt::Display for RootSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootSpecifier::Specifier(specifier) => write!(f, "{}", specifier),
+            RootSpecifier::Period(start, step) => write!(f, "{}/{}", start, step),
+            RootSpecifier::NamedPoint(name) => write!(f, "{}", name),
+            RootSpecifier::Last(0) => write!(f, "L"),
+            RootSpecifier::Last(offset) => write!(f, "L-{}", offset),
+            RootSpecifier::LastWeekday => write!(f, "LW"),
+            RootSpecifier::NearestWeekday(ordinal) => write!(f, "{}W", ordinal),
+            RootSpecifier::LastDayOfWeek(ordinal) => write!(f, "{}L", ordinal),
+            RootSpecifier::NthDayOfWeek(ordinal, n) => write!(f, "{}#{}", ordinal, n),
+        }
+    }
+}
@@ -1,16 +1,78 @@
 use crate::ordinal::{Ordinal, OrdinalSet};
+use crate::schedule::days_in_month;
 use crate::time_unit::TimeUnitField;
+use chrono::{Datelike, NaiveDate, Weekday};
 use std::borrow::Cow;
 
+/// A day of the month which depends on the month being scheduled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayOfMonthRule {
+    /// The last day of the month, less an offset ('L', 'L-3').
+    Last(u32),
+    /// The last weekday of the month ('LW').
+    LastWeekday,
+    /// The weekday nearest to the given day, without leaving the month ('15W').
+    NearestWeekday(Ordinal),
+}
+
+impl DayOfMonthRule {
+    fn resolve(&self, month: Ordinal, year: Ordinal) -> Option<Ordinal> {
+        let last_day = days_in_month(month, year);
+        match *self {
+            DayOfMonthRule::Last(offset) => last_day.checked_sub(offset).filter(|day| *day >= 1),
+            DayOfMonthRule::LastWeekday => nearest_weekday(last_day, month, year),
+            DayOfMonthRule::NearestWeekday(day) if day <= last_day => {
+                nearest_weekday(day, month, year)
+            }
+            DayOfMonthRule::NearestWeekday(_) => None,
+        }
+    }
+}
+
+fn nearest_weekday(day: Ordinal, month: Ordinal, year: Ordinal) -> Option<Ordinal> {
+    let last_day = days_in_month(month, year);
+    let weekday = NaiveDate::from_ymd_opt(year as i32, month, day)?.weekday();
+    Some(match weekday {
+        Weekday::Sat if day == 1 => 3,
+        Weekday::Sat => day - 1,
+        Weekday::Sun if day == last_day => day - 2,
+        Weekday::Sun => day + 1,
+        _ => day,
+    })
+}
+
 #[derive(Clone, Debug, Eq)]
 pub struct DaysOfMonth {
     ordinals: Option<OrdinalSet>,
+    rules: Vec<DayOfMonthRule>,
+}
+
+impl DaysOfMonth {
+    pub(crate) fn with_rules(self, rules: Vec<DayOfMonthRule>) -> Self {
+        DaysOfMonth { rules, ..self }
+    }
+
+    pub(crate) fn rules(&self) -> &[DayOfMonthRule] {
+        &self.rules
+    }
+
+    /// The days of the given month included in the schedule, including those resolved by rules.
+    pub(crate) fn ordinals_in(&self, month: Ordinal, year: Ordinal) -> OrdinalSet {
+        let mut ordinals = self.ordinals();
+        ordinals.extend(
+            self.rules
+                .iter()
+                .filter_map(|rule| rule.resolve(month, year)),
+        );
+        ordinals
+    }
 }
 
 impl TimeUnitField for DaysOfMonth {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         DaysOfMonth {
             ordinals: ordinal_set,
+            rules: vec![],
         }
     }
     fn name() -> Cow<'static, str> {
@@ -32,6 +94,6 @@ impl TimeUnitField for DaysOfMonth {
 
 impl PartialEq for DaysOfMonth {
     fn eq(&self, other: &DaysOfMonth) -> bool {
-        self.ordinals() == other.ordinals()
+        self.ordinals() == other.ordinals() && self.rules == other.rules
     }
 }
@@ -1,17 +1,62 @@
 use crate::error::*;
 use crate::ordinal::{Ordinal, OrdinalSet};
+use crate::schedule::days_in_month;
 use crate::time_unit::TimeUnitField;
+use chrono::{Datelike, NaiveDate};
 use std::borrow::Cow;
 
+/// A day of the week which depends on the month being scheduled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayOfWeekRule {
+    /// The last given day of the week in the month ('6L', 'FRIL').
+    Last(Ordinal),
+    /// The nth given day of the week in the month ('6#3', 'FRI#3').
+    Nth(Ordinal, u32),
+}
+
+impl DayOfWeekRule {
+    fn matches(&self, date: &NaiveDate) -> bool {
+        let day_of_week = date.weekday().number_from_sunday();
+        match *self {
+            DayOfWeekRule::Last(ordinal) => {
+                ordinal == day_of_week
+                    && date.day() + 7 > days_in_month(date.month(), date.year() as u32)
+            }
+            DayOfWeekRule::Nth(ordinal, n) => {
+                ordinal == day_of_week && (date.day() - 1) / 7 + 1 == n
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq)]
 pub struct DaysOfWeek {
     ordinals: Option<OrdinalSet>,
+    rules: Vec<DayOfWeekRule>,
+}
+
+impl DaysOfWeek {
+    pub(crate) fn with_rules(self, rules: Vec<DayOfWeekRule>) -> Self {
+        DaysOfWeek { rules, ..self }
+    }
+
+    pub(crate) fn rules(&self) -> &[DayOfWeekRule] {
+        &self.rules
+    }
+
+    /// Returns true if the given date falls on a day of the week included in the schedule.
+    pub(crate) fn matches(&self, date: &NaiveDate) -> bool {
+        self.ordinals()
+            .contains(&date.weekday().number_from_sunday())
+            || self.rules.iter().any(|rule| rule.matches(date))
+    }
 }
 
 impl TimeUnitField for DaysOfWeek {
     fn from_optional_ordinal_set(ordinal_set: Option<OrdinalSet>) -> Self {
         DaysOfWeek {
             ordinals: ordinal_set,
+            rules: vec![],
         }
     }
     fn name() -> Cow<'static, str> {
@@ -23,7 +68,7 @@ impl TimeUnitField for DaysOfWeek {
     fn inclusive_max() -> Ordinal {
         7
     }
-    fn ordinal_from_name(name: &str) -> Result<Ordinal, Error> {
+    fn ordinal_from_name(name: &str) -> Result<Ordinal, Reason> {
         //TODO: Use phf crate
         let ordinal = match name.to_lowercase().as_ref() {
             "sun" | "sunday" => 1,
@@ -33,13 +78,7 @@ impl TimeUnitField for DaysOfWeek {
             "thu" | "thurs" | "thursday" => 5,
             "fri" | "friday" => 6,
             "sat" | "saturday" => 7,
-            _ => {
-                return Err(ErrorKind::Expression(format!(
-                    "'{}' is not a valid day of the week.",
-                    name
-                ))
-                .into())
-            }
+            _ => return Err(Reason::UnknownName(name.to_owned())),
         };
         Ok(ordinal)
     }
@@ -53,6 +92,6 @@ impl TimeUnitField for DaysOfWeek {
 
 impl PartialEq for DaysOfWeek {
     fn eq(&self, other: &DaysOfWeek) -> bool {
-        self.ordinals() == other.ordinals()
+        self.ordinals() == other.ordinals() && self.rules == other.rules
     }
 }
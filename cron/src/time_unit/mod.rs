@@ -6,8 +6,8 @@ mod months;
 mod seconds;
 mod years;
 
-pub use self::days_of_month::DaysOfMonth;
-pub use self::days_of_week::DaysOfWeek;
+pub use self::days_of_month::{DayOfMonthRule, DaysOfMonth};
+pub use self::days_of_week::{DayOfWeekRule, DaysOfWeek};
 pub use self::hours::Hours;
 pub use self::minutes::Minutes;
 pub use self::months::Months;
@@ -126,76 +126,59 @@ where
         Self::from_optional_ordinal_set(Some(ordinal_set))
     }
 
-    fn ordinal_from_name(name: &str) -> Result<Ordinal, Error> {
-        Err(ErrorKind::Expression(format!(
-            "The '{}' field does not support using names. '{}' \
-             specified.",
-            Self::name(),
-            name
-        ))
-        .into())
+    fn ordinal_from_name(name: &str) -> Result<Ordinal, Reason> {
+        Err(Reason::Unsupported(name.to_owned()))
     }
 
-    fn validate_ordinal(ordinal: Ordinal) -> Result<Ordinal, Error> {
+    fn validate_ordinal(ordinal: Ordinal) -> Result<Ordinal, Reason> {
         //println!("validate_ordinal for {} => {}", Self::name(), ordinal);
         match ordinal {
-            i if i < Self::inclusive_min() => Err(ErrorKind::Expression(format!(
-                "{} must be greater than or equal to {}. ('{}' \
-                 specified.)",
-                Self::name(),
-                Self::inclusive_min(),
-                i
-            ))
-            .into()),
-            i if i > Self::inclusive_max() => Err(ErrorKind::Expression(format!(
-                "{} must be less than {}. ('{}' specified.)",
-                Self::name(),
-                Self::inclusive_max(),
-                i
-            ))
-            .into()),
+            i if i < Self::inclusive_min() || i > Self::inclusive_max() => {
+                Err(Reason::OutOfRange {
+                    ordinal: i,
+                    min: Self::inclusive_min(),
+                    max: Self::inclusive_max(),
+                })
+            }
             i => Ok(i),
         }
     }
 
-    fn ordinals_from_specifier(specifier: &Specifier) -> Result<OrdinalSet, Error> {
+    fn ordinals_from_specifier(specifier: &Specifier) -> Result<OrdinalSet, Reason> {
         use self::Specifier::*;
         //println!("ordinals_from_specifier for {} => {:?}", Self::name(), specifier);
         match *specifier {
             All => Ok(Self::supported_ordinals().clone()),
             Point(ordinal) => Ok((&[ordinal]).iter().cloned().collect()),
             Range(start, end) => {
-                match (Self::validate_ordinal(start), Self::validate_ordinal(end)) {
-                    (Ok(start), Ok(end)) if start <= end => Ok((start..end + 1).collect()),
-                    _ => Err(ErrorKind::Expression(format!(
-                        "Invalid range for {}: {}-{}",
-                        Self::name(),
-                        start,
-                        end
-                    ))
-                    .into()),
+                let start = Self::validate_ordinal(start)?;
+                let end = Self::validate_ordinal(end)?;
+                match start <= end {
+                    true => Ok((start..end + 1).collect()),
+                    false => Err(Reason::InvalidRange {
+                        start: start.to_string(),
+                        end: end.to_string(),
+                    }),
                 }
             }
             NamedRange(ref start_name, ref end_name) => {
-                let start = Self::ordinal_from_name(start_name)?;
-                let end = Self::ordinal_from_name(end_name)?;
-                match (Self::validate_ordinal(start), Self::validate_ordinal(end)) {
-                    (Ok(start), Ok(end)) if start <= end => Ok((start..end + 1).collect()),
-                    _ => Err(ErrorKind::Expression(format!(
-                        "Invalid named range for {}: {}-{}",
-                        Self::name(),
-                        start_name,
-                        end_name
-                    ))
-                    .into()),
+                let start = Self::validate_ordinal(Self::ordinal_from_name(start_name)?)?;
+                let end = Self::validate_ordinal(Self::ordinal_from_name(end_name)?)?;
+                match start <= end {
+                    true => Ok((start..end + 1).collect()),
+                    false => Err(Reason::InvalidRange {
+                        start: start_name.clone(),
+                        end: end_name.clone(),
+                    }),
                 }
             }
         }
     }
 
-    fn ordinals_from_root_specifier(root_specifier: &RootSpecifier) -> Result<OrdinalSet, Error> {
+    fn ordinals_from_root_specifier(root_specifier: &RootSpecifier) -> Result<OrdinalSet, Reason> {
         let ordinals = match root_specifier {
             RootSpecifier::Specifier(specifier) => Self::ordinals_from_specifier(specifier)?,
+            RootSpecifier::Period(_, 0) => return Err(Reason::InvalidStep(0)),
             RootSpecifier::Period(start, step) => {
                 let base_set = match start {
                     // A point prior to a period implies a range whose start is the specified
@@ -212,6 +195,8 @@ where
                 .iter()
                 .cloned()
                 .collect::<OrdinalSet>(),
+            // These depend on the month, so are resolved by the days of the month and week.
+            specifier => return Err(Reason::Unsupported(specifier.to_string())),
         };
         Ok(ordinals)
     }
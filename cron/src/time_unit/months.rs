@@ -23,7 +23,7 @@ impl TimeUnitField for Months {
     fn inclusive_max() -> Ordinal {
         12
     }
-    fn ordinal_from_name(name: &str) -> Result<Ordinal, Error> {
+    fn ordinal_from_name(name: &str) -> Result<Ordinal, Reason> {
         //TODO: Use phf crate
         let ordinal = match name.to_lowercase().as_ref() {
             "jan" | "january" => 1,
@@ -38,11 +38,7 @@ impl TimeUnitField for Months {
             "oct" | "october" => 10,
             "nov" | "november" => 11,
             "dec" | "december" => 12,
-            _ => {
-                return Err(
-                    ErrorKind::Expression(format!("'{}' is not a valid month name.", name)).into(),
-                )
-            }
+            _ => return Err(Reason::UnknownName(name.to_owned())),
         };
         Ok(ordinal)
     }
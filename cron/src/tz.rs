@@ -0,0 +1,338 @@
+use chrono::{
+    Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc,
+};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, ErrorKind};
+
+const HOUR: i32 = 3600;
+const MINUTE: i32 = 60;
+
+// The embedded time zone table. Each zone records its current standard offset and daylight saving
+// rules, in the style of a POSIX TZ string (e.g. "EST5EDT,M3.2.0,M11.1.0"). Historical changes to a
+// zone's rules are not recorded, which keeps the table small enough to use in the Solana runtime.
+static ZONES: &[Zone] = &[
+    Zone::fixed("UTC", 0),
+    Zone::fixed("Etc/UTC", 0),
+    Zone::fixed("Africa/Cairo", 2 * HOUR).with_dst(rule(4, 5, 5, 0), rule(10, 5, 4, 24)),
+    Zone::fixed("Africa/Johannesburg", 2 * HOUR),
+    Zone::fixed("Africa/Lagos", HOUR),
+    Zone::fixed("Africa/Nairobi", 3 * HOUR),
+    Zone::fixed("America/Anchorage", -9 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Argentina/Buenos_Aires", -3 * HOUR),
+    Zone::fixed("America/Bogota", -5 * HOUR),
+    Zone::fixed("America/Caracas", -4 * HOUR),
+    Zone::fixed("America/Chicago", -6 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Denver", -7 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Halifax", -4 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Lima", -5 * HOUR),
+    Zone::fixed("America/Los_Angeles", -8 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Mexico_City", -6 * HOUR),
+    Zone::fixed("America/New_York", -5 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Phoenix", -7 * HOUR),
+    Zone::fixed("America/Santiago", -4 * HOUR).with_dst(rule(9, 1, 6, 24), rule(4, 1, 6, 24)),
+    Zone::fixed("America/Sao_Paulo", -3 * HOUR),
+    Zone::fixed("America/St_Johns", -3 * HOUR - 30 * MINUTE)
+        .with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Toronto", -5 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("America/Vancouver", -8 * HOUR).with_dst(rule(3, 2, 0, 2), rule(11, 1, 0, 2)),
+    Zone::fixed("Asia/Bangkok", 7 * HOUR),
+    Zone::fixed("Asia/Dhaka", 6 * HOUR),
+    Zone::fixed("Asia/Dubai", 4 * HOUR),
+    Zone::fixed("Asia/Hong_Kong", 8 * HOUR),
+    Zone::fixed("Asia/Jakarta", 7 * HOUR),
+    Zone::fixed("Asia/Jerusalem", 2 * HOUR).with_dst(rule(3, 4, 4, 26), rule(10, 5, 0, 2)),
+    Zone::fixed("Asia/Karachi", 5 * HOUR),
+    Zone::fixed("Asia/Kolkata", 5 * HOUR + 30 * MINUTE),
+    Zone::fixed("Asia/Manila", 8 * HOUR),
+    Zone::fixed("Asia/Seoul", 9 * HOUR),
+    Zone::fixed("Asia/Shanghai", 8 * HOUR),
+    Zone::fixed("Asia/Singapore", 8 * HOUR),
+    Zone::fixed("Asia/Taipei", 8 * HOUR),
+    Zone::fixed("Asia/Tehran", 3 * HOUR + 30 * MINUTE),
+    Zone::fixed("Asia/Tokyo", 9 * HOUR),
+    Zone::fixed("Australia/Adelaide", 9 * HOUR + 30 * MINUTE)
+        .with_dst(rule(10, 1, 0, 2), rule(4, 1, 0, 3)),
+    Zone::fixed("Australia/Brisbane", 10 * HOUR),
+    Zone::fixed("Australia/Darwin", 9 * HOUR + 30 * MINUTE),
+    Zone::fixed("Australia/Hobart", 10 * HOUR).with_dst(rule(10, 1, 0, 2), rule(4, 1, 0, 3)),
+    Zone::fixed("Australia/Melbourne", 10 * HOUR).with_dst(rule(10, 1, 0, 2), rule(4, 1, 0, 3)),
+    Zone::fixed("Australia/Perth", 8 * HOUR),
+    Zone::fixed("Australia/Sydney", 10 * HOUR).with_dst(rule(10, 1, 0, 2), rule(4, 1, 0, 3)),
+    Zone::fixed("Europe/Amsterdam", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Athens", 2 * HOUR).with_dst(rule(3, 5, 0, 3), rule(10, 5, 0, 4)),
+    Zone::fixed("Europe/Berlin", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Brussels", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Copenhagen", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Dublin", 0).with_dst(rule(3, 5, 0, 1), rule(10, 5, 0, 2)),
+    Zone::fixed("Europe/Helsinki", 2 * HOUR).with_dst(rule(3, 5, 0, 3), rule(10, 5, 0, 4)),
+    Zone::fixed("Europe/Istanbul", 3 * HOUR),
+    Zone::fixed("Europe/Kyiv", 2 * HOUR).with_dst(rule(3, 5, 0, 3), rule(10, 5, 0, 4)),
+    Zone::fixed("Europe/Lisbon", 0).with_dst(rule(3, 5, 0, 1), rule(10, 5, 0, 2)),
+    Zone::fixed("Europe/London", 0).with_dst(rule(3, 5, 0, 1), rule(10, 5, 0, 2)),
+    Zone::fixed("Europe/Madrid", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Moscow", 3 * HOUR),
+    Zone::fixed("Europe/Oslo", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Paris", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Prague", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Rome", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Stockholm", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Vienna", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Warsaw", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Europe/Zurich", HOUR).with_dst(rule(3, 5, 0, 2), rule(10, 5, 0, 3)),
+    Zone::fixed("Pacific/Auckland", 12 * HOUR).with_dst(rule(9, 5, 0, 2), rule(4, 1, 0, 3)),
+    Zone::fixed("Pacific/Honolulu", -10 * HOUR),
+];
+
+/// Builds the rule for a DST transition on the given week (1-4, or 5 for the last) and day of the
+/// week (0 for Sunday) of a month, at the given hour of local wall clock time.
+const fn rule(month: u32, week: u32, weekday: u32, hour: i64) -> TransitionRule {
+    TransitionRule {
+        month,
+        week,
+        weekday,
+        time: hour * HOUR as i64,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Zone {
+    name: &'static str,
+    // The offset from UTC, in seconds, while standard time is in effect.
+    offset: i32,
+    dst: Option<DstRule>,
+}
+
+impl Zone {
+    const fn fixed(name: &'static str, offset: i32) -> Zone {
+        Zone {
+            name,
+            offset,
+            dst: None,
+        }
+    }
+
+    // All zones in the table observe a one hour daylight saving shift.
+    const fn with_dst(self, start: TransitionRule, end: TransitionRule) -> Zone {
+        Zone {
+            dst: Some(DstRule {
+                offset: self.offset + HOUR,
+                start,
+                end,
+            }),
+            ..self
+        }
+    }
+
+    // The offset from UTC, in seconds, in effect at the given moment.
+    fn offset_at(&self, utc: &NaiveDateTime) -> i32 {
+        let dst = match self.dst {
+            None => return self.offset,
+            Some(dst) => dst,
+        };
+        let year = (*utc + Duration::seconds(self.offset as i64)).year();
+        let (start, end) = match (dst.start.local_datetime(year), dst.end.local_datetime(year)) {
+            (Some(start), Some(end)) => (
+                start - Duration::seconds(self.offset as i64),
+                end - Duration::seconds(dst.offset as i64),
+            ),
+            // Years outside of the range chrono can represent fall back to standard time.
+            _ => return self.offset,
+        };
+        let is_dst = if start < end {
+            *utc >= start && *utc < end
+        } else {
+            // Southern hemisphere zones observe DST across the new year.
+            *utc < end || *utc >= start
+        };
+        if is_dst {
+            dst.offset
+        } else {
+            self.offset
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DstRule {
+    // The offset from UTC, in seconds, while daylight saving time is in effect.
+    offset: i32,
+    // The transition into DST, in local standard time.
+    start: TransitionRule,
+    // The transition out of DST, in local daylight time.
+    end: TransitionRule,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TransitionRule {
+    month: u32,
+    week: u32,
+    weekday: u32,
+    // Seconds after local midnight. May exceed 24 hours (e.g. "the end of Thursday").
+    time: i64,
+}
+
+impl TransitionRule {
+    fn local_datetime(&self, year: i32) -> Option<NaiveDateTime> {
+        let first_weekday = NaiveDate::from_ymd_opt(year, self.month, 1)?
+            .weekday()
+            .num_days_from_sunday();
+        let mut day = 1 + (self.weekday + 7 - first_weekday) % 7 + 7 * (self.week - 1);
+        while NaiveDate::from_ymd_opt(year, self.month, day).is_none() {
+            day -= 7;
+        }
+        let midnight = NaiveDate::from_ymd_opt(year, self.month, day)?.and_hms_opt(0, 0, 0)?;
+        Some(midnight + Duration::seconds(self.time))
+    }
+}
+
+/// An IANA time zone, such as `America/New_York`, which observes daylight saving time.
+///
+/// Time zones are looked up by name in a compact table embedded in this crate, which records the
+/// current rules of commonly used zones. This is not a full copy of the IANA time zone database:
+///
+/// - Only the zones listed by [Tz::names](struct.Tz.html#method.names) are supported. Parsing
+///   any other name fails with an "Unsupported time zone" error.
+/// - Each zone applies its current offset and daylight saving rules to every year. Times before a
+///   zone last changed its rules may resolve to a different offset than the IANA database gives.
+///
+/// ```
+/// use clockwork_cron::{Schedule, Tz};
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use std::str::FromStr;
+///
+/// let utc = |day, hour| {
+///     let date = NaiveDate::from_ymd_opt(2022, 3, day).unwrap();
+///     Utc.from_utc_datetime(&date.and_hms_opt(hour, 0, 0).unwrap())
+/// };
+/// let tz = Tz::from_str("America/New_York").unwrap();
+/// let schedule = Schedule::from_str("0 0 9 * * *").unwrap();
+/// let after = utc(12, 15).with_timezone(&tz);
+///
+/// // 9am in New York is 14:00 UTC in winter and 13:00 UTC in summer.
+/// let next = schedule.next_after(&after).unwrap();
+/// assert_eq!(next.with_timezone(&Utc), utc(13, 13));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Tz {
+    zone: &'static Zone,
+}
+
+impl Tz {
+    /// The IANA name of the time zone.
+    pub fn name(&self) -> &'static str {
+        self.zone.name
+    }
+
+    /// The names of all supported time zones.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        ZONES.iter().map(|zone| zone.name)
+    }
+
+    fn offset(&self, seconds: i32) -> TzOffset {
+        TzOffset {
+            tz: *self,
+            // Offsets in the zone table are all well within a day.
+            offset: FixedOffset::east_opt(seconds).unwrap_or_else(|| Utc.fix()),
+        }
+    }
+}
+
+impl FromStr for Tz {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        ZONES
+            .iter()
+            .find(|zone| zone.name.eq(name))
+            .map(|zone| Tz { zone })
+            .ok_or_else(|| ErrorKind::UnknownTimeZone(name.to_string()).into())
+    }
+}
+
+impl fmt::Debug for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.zone.name)
+    }
+}
+
+impl fmt::Display for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.zone.name)
+    }
+}
+
+/// The offset from UTC of a [Tz](struct.Tz.html) at a particular moment.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TzOffset {
+    tz: Tz,
+    offset: FixedOffset,
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Debug for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.offset)
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.offset)
+    }
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Tz {
+        offset.tz
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzOffset> {
+        // DST transitions never occur at midday.
+        let offset = local
+            .and_hms_opt(12, 0, 0)
+            .and_then(|midday| self.offset_from_local_datetime(&midday).earliest())
+            .unwrap_or_else(|| self.offset(self.zone.offset));
+        LocalResult::Single(offset)
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzOffset> {
+        // A local time is valid under an offset if the zone observes that offset at the resulting moment.
+        let is_valid = |offset: i32| {
+            self.zone
+                .offset_at(&(*local - Duration::seconds(offset as i64)))
+                .eq(&offset)
+        };
+        let dst_offset = self.zone.dst.map(|dst| dst.offset);
+        match (
+            is_valid(self.zone.offset),
+            dst_offset.filter(|o| is_valid(*o)),
+        ) {
+            (false, None) => LocalResult::None,
+            (true, None) => LocalResult::Single(self.offset(self.zone.offset)),
+            (false, Some(dst_offset)) => LocalResult::Single(self.offset(dst_offset)),
+            // The daylight offset is larger, so its moment is the earlier of the two.
+            (true, Some(dst_offset)) => {
+                LocalResult::Ambiguous(self.offset(dst_offset), self.offset(self.zone.offset))
+            }
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzOffset {
+        utc.and_hms_opt(0, 0, 0)
+            .map(|midnight| self.offset_from_utc_datetime(&midnight))
+            .unwrap_or_else(|| self.offset(self.zone.offset))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        self.offset(self.zone.offset_at(utc))
+    }
+}
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use chrono::*;
-    use clockwork_cron::{Schedule, TimeUnitSpec};
+    use clockwork_cron::error::{ErrorKind, Reason, TimeUnit};
+    use clockwork_cron::{Schedule, TimeUnitSpec, Tz};
     use std::str::FromStr;
 
     #[test]
@@ -190,4 +191,463 @@ mod tests {
         assert!(schedule.minutes().is_all());
         assert!(schedule.seconds().is_all());
     }
+
+    #[test]
+    fn test_time_zone_across_dst() {
+        let tz = Tz::from_str("America/New_York").unwrap();
+        let schedule = Schedule::from_str("0 0 9 * * *").unwrap();
+        let start_time = Utc.ymd(2022, 3, 12).and_hms(0, 0, 0).with_timezone(&tz);
+        let times = schedule
+            .after(&start_time)
+            .take(2)
+            .map(|t| t.with_timezone(&Utc))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            times,
+            vec![
+                Utc.ymd(2022, 3, 12).and_hms(14, 0, 0),
+                Utc.ymd(2022, 3, 13).and_hms(13, 0, 0),
+            ]
+        );
+
+        let start_time = Utc.ymd(2022, 11, 5).and_hms(0, 0, 0).with_timezone(&tz);
+        let times = schedule
+            .after(&start_time)
+            .take(2)
+            .map(|t| t.with_timezone(&Utc))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            times,
+            vec![
+                Utc.ymd(2022, 11, 5).and_hms(13, 0, 0),
+                Utc.ymd(2022, 11, 6).and_hms(14, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_time_zone_skips_missing_local_time() {
+        let tz = Tz::from_str("America/New_York").unwrap();
+        let schedule = Schedule::from_str("0 30 2 * * *").unwrap();
+        let start_time = Utc.ymd(2022, 3, 12).and_hms(12, 0, 0).with_timezone(&tz);
+        let next_time = schedule.after(&start_time).next().unwrap();
+        assert_eq!(
+            next_time.with_timezone(&Utc),
+            Utc.ymd(2022, 3, 14).and_hms(6, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_time_zone_repeated_local_time() {
+        let tz = Tz::from_str("America/New_York").unwrap();
+
+        // A daily schedule fires once during the repeated hour.
+        let schedule = Schedule::from_str("0 30 1 * * *").unwrap();
+        let start_time = Utc.ymd(2022, 11, 5).and_hms(12, 0, 0).with_timezone(&tz);
+        let times = schedule
+            .after(&start_time)
+            .take(2)
+            .map(|t| t.with_timezone(&Utc))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            times,
+            vec![
+                Utc.ymd(2022, 11, 6).and_hms(5, 30, 0),
+                Utc.ymd(2022, 11, 7).and_hms(6, 30, 0),
+            ]
+        );
+
+        // An hourly schedule fires every hour through the transition.
+        let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+        let start_time = Utc.ymd(2022, 11, 6).and_hms(4, 30, 0).with_timezone(&tz);
+        let times = schedule
+            .after(&start_time)
+            .take(3)
+            .map(|t| t.with_timezone(&Utc))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            times,
+            vec![
+                Utc.ymd(2022, 11, 6).and_hms(5, 0, 0),
+                Utc.ymd(2022, 11, 6).and_hms(6, 0, 0),
+                Utc.ymd(2022, 11, 6).and_hms(7, 0, 0),
+            ]
+        );
+
+        // And in reverse.
+        let start_time = Utc.ymd(2022, 11, 6).and_hms(7, 30, 0).with_timezone(&tz);
+        let prev_time = schedule.prev_before(&start_time).unwrap();
+        assert_eq!(
+            prev_time.with_timezone(&Utc),
+            Utc.ymd(2022, 11, 6).and_hms(7, 0, 0)
+        );
+        let prev_time = schedule.prev_before(&prev_time).unwrap();
+        assert_eq!(
+            prev_time.with_timezone(&Utc),
+            Utc.ymd(2022, 11, 6).and_hms(6, 0, 0)
+        );
+        let prev_time = schedule.prev_before(&prev_time).unwrap();
+        assert_eq!(
+            prev_time.with_timezone(&Utc),
+            Utc.ymd(2022, 11, 6).and_hms(5, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_time_zone_southern_hemisphere() {
+        let tz = Tz::from_str("Australia/Sydney").unwrap();
+        let schedule = Schedule::from_str("0 0 9 * * *").unwrap();
+        let start_time = Utc.ymd(2022, 9, 30).and_hms(12, 0, 0).with_timezone(&tz);
+        let times = schedule
+            .after(&start_time)
+            .take(2)
+            .map(|t| t.with_timezone(&Utc))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            times,
+            vec![
+                Utc.ymd(2022, 9, 30).and_hms(23, 0, 0),
+                Utc.ymd(2022, 10, 1).and_hms(22, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_time_zone() {
+        let err = Tz::from_str("Mars/Olympus_Mons").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Unsupported time zone: Mars/Olympus_Mons"));
+    }
+
+    #[test]
+    fn test_time_zone_names() {
+        assert!(Tz::names().any(|name| name.eq("America/New_York")));
+        for name in Tz::names() {
+            assert_eq!(Tz::from_str(name).unwrap().name(), name);
+        }
+    }
+
+    fn next_days(expression: &str, start_time: DateTime<Utc>, n: usize) -> Vec<NaiveDate> {
+        Schedule::from_str(expression)
+            .unwrap()
+            .after(&start_time)
+            .take(n)
+            .map(|datetime| datetime.naive_utc().date())
+            .collect()
+    }
+
+    #[test]
+    fn test_last_day_of_month() {
+        let start_time = Utc.ymd(2024, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(
+            next_days("0 0 12 L * ?", start_time, 3),
+            vec![
+                NaiveDate::from_ymd(2024, 1, 31),
+                NaiveDate::from_ymd(2024, 2, 29),
+                NaiveDate::from_ymd(2024, 3, 31),
+            ]
+        );
+        assert_eq!(
+            next_days("0 0 12 L-2 * ?", start_time, 2),
+            vec![
+                NaiveDate::from_ymd(2024, 1, 29),
+                NaiveDate::from_ymd(2024, 2, 27),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_last_weekday_of_month() {
+        // Mar 31 2024 is a Sunday and Aug 31 2024 is a Saturday.
+        let start_time = Utc.ymd(2024, 3, 1).and_hms(0, 0, 0);
+        assert_eq!(
+            next_days("0 0 12 LW 3,4,8 ?", start_time, 3),
+            vec![
+                NaiveDate::from_ymd(2024, 3, 29),
+                NaiveDate::from_ymd(2024, 4, 30),
+                NaiveDate::from_ymd(2024, 8, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nearest_weekday() {
+        // Jun 15 2024 is a Saturday, Sep 15 2024 is a Sunday and Jun 1 2024 is a Saturday.
+        let start_time = Utc.ymd(2024, 5, 20).and_hms(0, 0, 0);
+        assert_eq!(
+            next_days("0 0 12 15W 6,7,9 ?", start_time, 3),
+            vec![
+                NaiveDate::from_ymd(2024, 6, 14),
+                NaiveDate::from_ymd(2024, 7, 15),
+                NaiveDate::from_ymd(2024, 9, 16),
+            ]
+        );
+        assert_eq!(
+            next_days("0 0 12 1W 6 ?", start_time, 1),
+            vec![NaiveDate::from_ymd(2024, 6, 3)]
+        );
+    }
+
+    #[test]
+    fn test_nth_day_of_week() {
+        let start_time = Utc.ymd(2024, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(
+            next_days("0 0 16 ? * FRI#3", start_time, 3),
+            vec![
+                NaiveDate::from_ymd(2024, 1, 19),
+                NaiveDate::from_ymd(2024, 2, 16),
+                NaiveDate::from_ymd(2024, 3, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_last_day_of_week() {
+        let start_time = Utc.ymd(2024, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(
+            next_days("0 0 16 ? * FRIL", start_time, 3),
+            vec![
+                NaiveDate::from_ymd(2024, 1, 26),
+                NaiveDate::from_ymd(2024, 2, 23),
+                NaiveDate::from_ymd(2024, 3, 29),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extended_prev_before() {
+        let schedule = Schedule::from_str("0 0 16 ? * FRI#3").unwrap();
+        let start_time = Utc.ymd(2024, 3, 1).and_hms(0, 0, 0);
+        let prev_time = schedule.prev_before(&start_time).unwrap();
+        assert_eq!(prev_time, Utc.ymd(2024, 2, 16).and_hms(16, 0, 0));
+
+        let schedule = Schedule::from_str("0 0 12 L * ?").unwrap();
+        let prev_time = schedule.prev_before(&start_time).unwrap();
+        assert_eq!(prev_time, Utc.ymd(2024, 2, 29).and_hms(12, 0, 0));
+    }
+
+    #[test]
+    fn test_extended_includes() {
+        let schedule = Schedule::from_str("0 0 12 L * ?").unwrap();
+        assert!(schedule.includes(Utc.ymd(2024, 2, 29).and_hms(12, 0, 0)));
+        assert!(!schedule.includes(Utc.ymd(2024, 2, 28).and_hms(12, 0, 0)));
+
+        let schedule = Schedule::from_str("0 0 12 ? * FRI#3").unwrap();
+        assert!(schedule.includes(Utc.ymd(2024, 1, 19).and_hms(12, 0, 0)));
+        assert!(!schedule.includes(Utc.ymd(2024, 1, 26).and_hms(12, 0, 0)));
+    }
+
+    fn parse_error(expression: &str) -> ErrorKind {
+        Schedule::from_str(expression).unwrap_err().kind().clone()
+    }
+
+    #[test]
+    fn test_error_out_of_range_ordinal() {
+        assert_eq!(
+            parse_error("0 0 25 * * *"),
+            ErrorKind::Field {
+                field: TimeUnit::Hours,
+                offset: 4,
+                reason: Reason::OutOfRange {
+                    ordinal: 25,
+                    min: 0,
+                    max: 23
+                },
+            }
+        );
+        assert_eq!(
+            parse_error("0 0 0 1-40 * *"),
+            ErrorKind::Field {
+                field: TimeUnit::DaysOfMonth,
+                offset: 6,
+                reason: Reason::OutOfRange {
+                    ordinal: 40,
+                    min: 1,
+                    max: 31
+                },
+            }
+        );
+        assert_eq!(
+            parse_error("0 0 9,12,25 * * *"),
+            ErrorKind::Field {
+                field: TimeUnit::Hours,
+                offset: 9,
+                reason: Reason::OutOfRange {
+                    ordinal: 25,
+                    min: 0,
+                    max: 23
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_unknown_name() {
+        assert_eq!(
+            parse_error("0 0 0 * * MON,FUNDAY"),
+            ErrorKind::Field {
+                field: TimeUnit::DaysOfWeek,
+                offset: 14,
+                reason: Reason::UnknownName("FUNDAY".into()),
+            }
+        );
+        assert_eq!(
+            parse_error("0 0 0 * Smarch *"),
+            ErrorKind::Field {
+                field: TimeUnit::Months,
+                offset: 8,
+                reason: Reason::UnknownName("Smarch".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_bad_step() {
+        assert_eq!(
+            parse_error("*/0 * * * * *"),
+            ErrorKind::Field {
+                field: TimeUnit::Seconds,
+                offset: 0,
+                reason: Reason::InvalidStep(0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_syntax() {
+        assert_eq!(
+            parse_error("0 1,2% * * * *"),
+            ErrorKind::Field {
+                field: TimeUnit::Minutes,
+                offset: 5,
+                reason: Reason::Syntax("%".into()),
+            }
+        );
+        let error = Schedule::from_str("0 1,2% * * * *").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid minutes field at byte 5: unexpected '%'"
+        );
+
+        // Names are only supported by months and days of the week.
+        assert_eq!(
+            parse_error("0 x * * * *"),
+            ErrorKind::Field {
+                field: TimeUnit::Minutes,
+                offset: 2,
+                reason: Reason::Unsupported("x".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_invalid_range() {
+        assert_eq!(
+            parse_error("0 0 0 * * FRI-MON"),
+            ErrorKind::Field {
+                field: TimeUnit::DaysOfWeek,
+                offset: 10,
+                reason: Reason::InvalidRange {
+                    start: "FRI".into(),
+                    end: "MON".into()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_error_field_count() {
+        assert_eq!(
+            parse_error("* * * *"),
+            ErrorKind::Expression("Expected 6 or 7 fields, found 4.".into())
+        );
+    }
+
+    #[test]
+    fn test_between() {
+        let schedule = Schedule::from_str("0 0 * * * *").unwrap();
+        let after = Utc.ymd(2022, 1, 1).and_hms(0, 0, 0);
+        let until = Utc.ymd(2022, 1, 1).and_hms(3, 0, 0);
+        let times: Vec<_> = schedule.between(&after, &until).collect();
+        assert_eq!(
+            times,
+            vec![
+                Utc.ymd(2022, 1, 1).and_hms(1, 0, 0),
+                Utc.ymd(2022, 1, 1).and_hms(2, 0, 0),
+                Utc.ymd(2022, 1, 1).and_hms(3, 0, 0),
+            ]
+        );
+
+        // The window is exclusive of its start and inclusive of its end.
+        assert_eq!(schedule.count_between(&after, &until), 3);
+        assert_eq!(schedule.count_between(&until, &until), 0);
+        assert_eq!(
+            schedule.count_between(&after, &Utc.ymd(2022, 1, 2).and_hms(0, 0, 0)),
+            24
+        );
+    }
+
+    #[test]
+    fn test_difference() {
+        let every_weekday = Schedule::from_str("0 0 9 * * Mon-Fri").unwrap();
+        let mon_wed_fri = Schedule::from_str("0 0 9 * * Mon,Wed,Fri").unwrap();
+        let after = Utc.ymd(2022, 6, 5).and_hms(0, 0, 0); // Sunday
+        let until = Utc.ymd(2022, 6, 12).and_hms(0, 0, 0);
+        let removed: Vec<_> = every_weekday
+            .difference(&mon_wed_fri, &after, &until)
+            .collect();
+        assert_eq!(
+            removed,
+            vec![
+                Utc.ymd(2022, 6, 7).and_hms(9, 0, 0),
+                Utc.ymd(2022, 6, 9).and_hms(9, 0, 0),
+            ]
+        );
+        assert_eq!(
+            mon_wed_fri
+                .difference(&every_weekday, &after, &until)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let describe = |expression| Schedule::from_str(expression).unwrap().describe();
+        assert_eq!(describe("* * * * * *"), "Every second");
+        assert_eq!(describe("0 0 * * * *"), "Every hour");
+        assert_eq!(describe("@weekly"), "At 00:00 on Sun");
+        assert_eq!(describe("0 0 3,9,22 * * *"), "At 03:00, 09:00 and 22:00");
+        assert_eq!(
+            describe("0 */15 9-17 * * Mon-Fri"),
+            "Every 15 minutes, at hours 9 through 17 on Mon through Fri"
+        );
+        assert_eq!(describe("0 0 9-17 * * *"), "At hours 9 through 17");
+        assert_eq!(
+            describe("0 * 9-17 * * *"),
+            "Every minute, between 09:00 and 17:59"
+        );
+        assert_eq!(
+            describe("0 0 0 1 1,7 * 2023-2025"),
+            "At 00:00 on day 1 of the month in January and July in 2023 through 2025"
+        );
+    }
+
+    #[test]
+    fn test_describe_extended_syntax() {
+        let describe = |expression| Schedule::from_str(expression).unwrap().describe();
+        assert_eq!(
+            describe("0 0 16 ? * FRI#3"),
+            "At 16:00 on the third Fri of the month"
+        );
+        assert_eq!(
+            describe("0 0 0 L-3,LW * *"),
+            "At 00:00 on the day 3 days before the last day and the last weekday of the month"
+        );
+        assert_eq!(
+            describe("0 0 9 13 * Fri"),
+            "At 09:00 on day 13 of the month, if it falls on Fri"
+        );
+    }
 }
@@ -1,34 +1,51 @@
 use {
     clockwork_client::{
         network::objects::Worker,
-        queue::objects::{Queue, Trigger, TriggerContext},
+        queue::objects::{hash_account_data, Queue, Trigger, TriggerContext},
         Client as ClockworkClient,
     },
     dashmap::DashSet,
     log::info,
     solana_account_decoder::UiAccountEncoding,
+    solana_address_lookup_table_program::state::AddressLookupTable,
     solana_client::rpc_config::{
         RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig,
     },
     solana_program::{
-        instruction::{AccountMeta, Instruction},
+        hash::Hash,
+        instruction::{AccountMeta, CompiledInstruction, Instruction},
+        message::{v0, Message, MessageHeader, VersionedMessage},
         pubkey::Pubkey,
     },
-    solana_sdk::{account::Account, commitment_config::CommitmentConfig, transaction::Transaction},
-    std::{
-        collections::hash_map::DefaultHasher,
-        hash::{Hash, Hasher},
-        sync::Arc,
+    solana_sdk::{
+        account::Account, commitment_config::CommitmentConfig,
+        compute_budget::ComputeBudgetInstruction, signer::Signer,
+        transaction::VersionedTransaction,
     },
+    std::sync::Arc,
 };
 
 static TRANSACTION_SIZE_LIMIT: usize = 1_232; // Max byte size of a serialized transaction
 
+/// The addresses stored in an address lookup table.
+struct LookupTable {
+    key: Pubkey,
+    addresses: Vec<Pubkey>,
+}
+
+/// An account referenced by a transaction, with the privileges the transaction requires of it.
+struct MessageKey {
+    pubkey: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+    is_invoked: bool,
+}
+
 pub async fn build_crank_txs(
     client: Arc<ClockworkClient>,
     crankable_queues: DashSet<Pubkey>,
     worker_id: u64,
-) -> Vec<Transaction> {
+) -> Vec<VersionedTransaction> {
     // Build the set of crank transactions
     // TODO Use rayon to parallelize this operation
     let txs = crankable_queues
@@ -36,7 +53,7 @@ pub async fn build_crank_txs(
         .filter_map(|queue_pubkey_ref| {
             build_crank_tx(client.clone(), *queue_pubkey_ref.key(), worker_id)
         })
-        .collect::<Vec<Transaction>>();
+        .collect::<Vec<VersionedTransaction>>();
     txs
 }
 
@@ -44,7 +61,7 @@ fn build_crank_tx(
     client: Arc<ClockworkClient>,
     queue_pubkey: Pubkey,
     worker_id: u64,
-) -> Option<Transaction> {
+) -> Option<VersionedTransaction> {
     // Build the first crank ix
     let queue = match client.get::<Queue>(&queue_pubkey) {
         Err(_err) => return None,
@@ -53,29 +70,61 @@ fn build_crank_tx(
     let blockhash = client.get_latest_blockhash().unwrap();
     let signatory_pubkey = client.payer_pubkey();
 
+    // Fetch the address lookup tables to compress the tx with.
+    let address_lookup_tables = queue
+        .address_lookup_tables
+        .iter()
+        .filter_map(|key| {
+            let data = client.get_account_data(key).ok()?;
+            let table = AddressLookupTable::deserialize(&data).ok()?;
+            Some(LookupTable {
+                key: *key,
+                addresses: table.addresses.to_vec(),
+            })
+        })
+        .collect::<Vec<LookupTable>>();
+
+    // Request the queue's compute budget for the tx.
+    let compute_unit_limit = queue.compute_unit_limit as u64;
+    let mut ixs: Vec<Instruction> = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+        queue.compute_unit_limit,
+    )];
+    if queue.compute_unit_price > 0 {
+        ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+            queue.compute_unit_price,
+        ));
+    }
+    let compute_budget_ix_count = ixs.len();
+
     // Pre-simulate crank ixs and pack into tx
-    let first_instruction = if queue.next_instruction.is_some() {
+    let first_instruction = if !queue.next_instructions.is_empty() {
         build_crank_ix(client.clone(), queue, signatory_pubkey, worker_id)
     } else {
-        build_kickoff_ix(client.clone(), queue, signatory_pubkey, worker_id)
+        build_kickoff_ix(client.clone(), queue, signatory_pubkey, worker_id)?
     };
-    let mut ixs: Vec<Instruction> = vec![first_instruction];
+    ixs.push(first_instruction);
 
     // Pre-simulate crank ixs and pack as many as possible into tx.
-    let mut tx: Transaction = Transaction::new_with_payer(&vec![], Some(&signatory_pubkey));
+    let mut tx: Option<VersionedTransaction> = None;
+    let mut units_consumed: u64 = 0;
     let now = std::time::Instant::now();
     loop {
-        let mut sim_tx = Transaction::new_with_payer(&ixs, Some(&signatory_pubkey));
-        sim_tx.sign(&[client.payer()], blockhash);
+        let sim_tx =
+            match build_versioned_tx(client.clone(), &ixs, &address_lookup_tables, blockhash) {
+                None => break,
+                Some(sim_tx) => sim_tx,
+            };
 
         // Exit early if tx exceeds Solana's size limit.
-        // TODO With QUIC and Transaction v2 lookup tables, Solana will soon support much larger transaction sizes.
-        if sim_tx.message_data().len() > TRANSACTION_SIZE_LIMIT {
-            break;
+        // TODO With QUIC, Solana will soon support much larger transaction sizes.
+        match bincode::serialized_size(&sim_tx) {
+            Ok(size) if size as usize <= TRANSACTION_SIZE_LIMIT => {}
+            _ => break,
         }
 
         // Simulate the complete packed tx.
-        match client.simulate_transaction_with_config(
+        match crate::utils::simulate_versioned_transaction(
+            &client,
             &sim_tx,
             RpcSimulateTransactionConfig {
                 replace_recent_blockhash: true,
@@ -105,14 +154,23 @@ fn build_crank_tx(
                 }
 
                 // Save the simulated tx. It is okay to submit.
-                tx = sim_tx;
+                tx = Some(sim_tx);
+
+                // Stop packing if another crank, as expensive as the last one, would exceed the compute unit limit.
+                if let Some(sim_units_consumed) = response.value.units_consumed {
+                    let crank_units_consumed = sim_units_consumed.saturating_sub(units_consumed);
+                    units_consumed = sim_units_consumed;
+                    if units_consumed.saturating_add(crank_units_consumed) > compute_unit_limit {
+                        break;
+                    }
+                }
 
                 // Parse the resulting queue account for the next crank ix to simulate.
                 if let Some(ui_accounts) = response.value.accounts {
                     if let Some(Some(ui_account)) = ui_accounts.get(0) {
                         if let Some(account) = ui_account.decode::<Account>() {
                             if let Ok(sim_queue) = Queue::try_from(account.data) {
-                                if sim_queue.next_instruction.is_some() {
+                                if !sim_queue.next_instructions.is_empty() {
                                     ixs.push(build_crank_ix(
                                         client.clone(),
                                         sim_queue,
@@ -131,16 +189,188 @@ fn build_crank_tx(
     }
 
     info!(
-        "Time spent packing {} cranks: {:#?}",
-        tx.message.instructions.len(),
+        "Time spent packing {} cranks ({} compute units): {:#?}",
+        tx.as_ref().map_or(0, |tx| tx
+            .message
+            .instructions()
+            .len()
+            .saturating_sub(compute_budget_ix_count)),
+        units_consumed,
         now.elapsed()
     );
 
-    if tx.message.instructions.len() == 0 {
-        return None;
+    tx
+}
+
+fn build_versioned_tx(
+    client: Arc<ClockworkClient>,
+    ixs: &[Instruction],
+    address_lookup_tables: &[LookupTable],
+    blockhash: Hash,
+) -> Option<VersionedTransaction> {
+    // Only compile a v0 message if the queue has lookup tables to compress the tx with.
+    let signatory_pubkey = client.payer_pubkey();
+    let message = if address_lookup_tables.is_empty() {
+        VersionedMessage::Legacy(Message::new_with_blockhash(
+            ixs,
+            Some(&signatory_pubkey),
+            &blockhash,
+        ))
+    } else {
+        VersionedMessage::V0(compile_v0_message(
+            signatory_pubkey,
+            ixs,
+            address_lookup_tables,
+            blockhash,
+        )?)
+    };
+
+    // Sign the message.
+    let signature = client.payer().try_sign_message(&message.serialize()).ok()?;
+    Some(VersionedTransaction {
+        signatures: vec![signature],
+        message,
+    })
+}
+
+// Compile a v0 message, loading the accounts which need not be static from the lookup tables.
+// Returns none if the message references more accounts than can be indexed.
+fn compile_v0_message(
+    payer: Pubkey,
+    ixs: &[Instruction],
+    address_lookup_tables: &[LookupTable],
+    blockhash: Hash,
+) -> Option<v0::Message> {
+    // Collect the accounts referenced by the ixs, merging their privileges. The payer comes first.
+    let mut keys: Vec<MessageKey> = vec![MessageKey {
+        pubkey: payer,
+        is_signer: true,
+        is_writable: true,
+        is_invoked: false,
+    }];
+    let metas = ixs.iter().flat_map(|ix| {
+        std::iter::once((ix.program_id, false, false, true)).chain(ix.accounts.iter().map(
+            |account| {
+                (
+                    account.pubkey,
+                    account.is_signer,
+                    account.is_writable,
+                    false,
+                )
+            },
+        ))
+    });
+    for (pubkey, is_signer, is_writable, is_invoked) in metas {
+        match keys.iter_mut().find(|key| key.pubkey.eq(&pubkey)) {
+            Some(key) => {
+                key.is_signer |= is_signer;
+                key.is_writable |= is_writable;
+                key.is_invoked |= is_invoked;
+            }
+            None => keys.push(MessageKey {
+                pubkey,
+                is_signer,
+                is_writable,
+                is_invoked,
+            }),
+        }
+    }
+
+    // Load every account which is neither a signer nor an invoked program from the first table containing it.
+    let mut address_table_lookups: Vec<v0::MessageAddressTableLookup> = vec![];
+    let mut loaded_writable_keys: Vec<Pubkey> = vec![];
+    let mut loaded_readonly_keys: Vec<Pubkey> = vec![];
+    for table in address_lookup_tables {
+        let mut writable_indexes: Vec<u8> = vec![];
+        let mut readonly_indexes: Vec<u8> = vec![];
+        keys.retain(|key| {
+            if key.is_signer || key.is_invoked {
+                return true;
+            }
+            let index = table
+                .addresses
+                .iter()
+                .position(|address| address.eq(&key.pubkey))
+                .and_then(|index| u8::try_from(index).ok());
+            match index {
+                None => true,
+                Some(index) => {
+                    if key.is_writable {
+                        writable_indexes.push(index);
+                        loaded_writable_keys.push(key.pubkey);
+                    } else {
+                        readonly_indexes.push(index);
+                        loaded_readonly_keys.push(key.pubkey);
+                    }
+                    false
+                }
+            }
+        });
+        if !writable_indexes.is_empty() || !readonly_indexes.is_empty() {
+            address_table_lookups.push(v0::MessageAddressTableLookup {
+                account_key: table.key,
+                writable_indexes,
+                readonly_indexes,
+            });
+        }
     }
 
-    Some(tx)
+    // Order the static accounts: writable signers, readonly signers, writable and then readonly non-signers.
+    // The sort is stable, so the payer stays first.
+    keys.sort_by_key(|key| (!key.is_signer, !key.is_writable));
+    let header = MessageHeader {
+        num_required_signatures: u8::try_from(keys.iter().filter(|key| key.is_signer).count())
+            .ok()?,
+        num_readonly_signed_accounts: u8::try_from(
+            keys.iter()
+                .filter(|key| key.is_signer && !key.is_writable)
+                .count(),
+        )
+        .ok()?,
+        num_readonly_unsigned_accounts: u8::try_from(
+            keys.iter()
+                .filter(|key| !key.is_signer && !key.is_writable)
+                .count(),
+        )
+        .ok()?,
+    };
+
+    // Accounts are indexed by the static accounts, then the loaded writable and readonly accounts.
+    let account_keys: Vec<Pubkey> = keys.iter().map(|key| key.pubkey).collect();
+    let indexed_keys: Vec<Pubkey> = account_keys
+        .iter()
+        .chain(loaded_writable_keys.iter())
+        .chain(loaded_readonly_keys.iter())
+        .cloned()
+        .collect();
+    let index_of = |pubkey: &Pubkey| -> Option<u8> {
+        indexed_keys
+            .iter()
+            .position(|key| key.eq(pubkey))
+            .and_then(|index| u8::try_from(index).ok())
+    };
+    let instructions = ixs
+        .iter()
+        .map(|ix| {
+            Some(CompiledInstruction {
+                program_id_index: index_of(&ix.program_id)?,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|account| index_of(&account.pubkey))
+                    .collect::<Option<Vec<u8>>>()?,
+                data: ix.data.clone(),
+            })
+        })
+        .collect::<Option<Vec<CompiledInstruction>>>()?;
+
+    Some(v0::Message {
+        header,
+        account_keys,
+        recent_blockhash: blockhash,
+        instructions,
+        address_table_lookups,
+    })
 }
 
 fn build_kickoff_ix(
@@ -148,46 +378,105 @@ fn build_kickoff_ix(
     queue: Queue,
     signatory_pubkey: Pubkey,
     worker_id: u64,
-) -> Instruction {
+) -> Option<Instruction> {
     // If this queue is an account listener, grab the account and create the data_hash.
     let mut trigger_account_pubkey: Option<Pubkey> = None;
     let mut data_hash: Option<u64> = None;
     match queue.trigger {
-        Trigger::Account { pubkey } => {
+        Trigger::Account { pubkey } | Trigger::AccountRange { pubkey, .. } => {
             // Save the trigger account.
             trigger_account_pubkey = Some(pubkey);
 
-            // Begin computing the data hash of this account.
-            let data = client.get_account_data(&pubkey).unwrap();
-            let mut hasher = DefaultHasher::new();
-            data.hash(&mut hasher);
+            // Fetch the observed account data.
+            // If the watched byte range is out of bounds, the queue program would reject the kickoff, so skip it.
+            let data = match client.get_account_data(&pubkey) {
+                Err(err) => {
+                    info!(
+                        "Failed to fetch trigger account {} of queue {}: {}",
+                        pubkey,
+                        Queue::pubkey(queue.creator, queue.id.clone()),
+                        err
+                    );
+                    return None;
+                }
+                Ok(data) => data,
+            };
+            let observed_data = queue.trigger.observed_data(&data)?;
 
             // Check the exec context for the prior data hash.
-            match queue.exec_context.clone() {
+            let prior_data_hash = match queue.exec_context.clone() {
                 None => {
                     // This queue has not begun executing yet.
                     // There is no prior data hash to include in our hash.
-                    data_hash = Some(hasher.finish());
+                    None
                 }
-                Some(exec_context) => {
-                    match exec_context.trigger_context {
-                        TriggerContext::Account {
-                            data_hash: prior_data_hash,
-                        } => {
-                            // Inject the prior data hash as a seed.
-                            prior_data_hash.hash(&mut hasher);
-                            data_hash = Some(hasher.finish());
-                        }
-                        _ => {}
+                Some(exec_context) => match exec_context.trigger_context {
+                    TriggerContext::Account { data_hash } => Some(data_hash),
+                    TriggerContext::VersionedAccount { data_hash, .. } => Some(data_hash),
+                    _ => None,
+                },
+            };
+
+            // Compute the data hash with the same function as the queue program.
+            data_hash = Some(hash_account_data(observed_data, prior_data_hash));
+        }
+        Trigger::Condition {
+            pubkey,
+            offset,
+            value_type,
+            endianness,
+            operator,
+            operand,
+        } => {
+            // Save the trigger account.
+            trigger_account_pubkey = Some(pubkey);
+
+            // Read the watched value from the current account data.
+            let data = client.get_account_data(&pubkey).ok()?;
+            let value = data
+                .get(offset as usize..)
+                .and_then(|data| value_type.read(data, endianness))?;
+
+            // Condition triggers fire once each time the condition starts to hold.
+            // Kickoff the queue if the condition holds and the queue is armed,
+            // or to arm the queue if the condition has stopped holding since the prior kickoff.
+            let armed = match queue.exec_context {
+                None => true,
+                Some(exec_context) => match exec_context.trigger_context {
+                    TriggerContext::Condition { armed, .. } => armed,
+                    _ => return None,
+                },
+            };
+            if operator.evaluate(value_type.compare(value, operand)) != armed {
+                return None;
+            }
+        }
+        Trigger::Queue { pubkey } => {
+            // Save the upstream queue account.
+            trigger_account_pubkey = Some(pubkey);
+
+            // Only kickoff the queue if the upstream queue has completed a new run.
+            let upstream_queue = client.get::<Queue>(&pubkey).ok()?;
+            if !upstream_queue.next_instructions.is_empty() {
+                return None;
+            }
+            let completed_at = upstream_queue.exec_context?.last_crank_at;
+            if let Some(exec_context) = queue.exec_context {
+                if let TriggerContext::Queue {
+                    completed_at: prior_completed_at,
+                } = exec_context.trigger_context
+                {
+                    if completed_at.le(&prior_completed_at) {
+                        return None;
                     }
                 }
-            };
+            }
         }
         _ => {}
     }
 
     // Build the instruction.
-    let queue_pubkey = Queue::pubkey(queue.authority, queue.id);
+    let queue_pubkey = Queue::pubkey(queue.creator, queue.id);
     let mut kickoff_ix = clockwork_client::queue::instruction::queue_kickoff(
         data_hash,
         queue_pubkey,
@@ -205,7 +494,7 @@ fn build_kickoff_ix(
         }),
     }
 
-    kickoff_ix
+    Some(kickoff_ix)
 }
 
 fn build_crank_ix(
@@ -215,14 +504,14 @@ fn build_crank_ix(
     worker_id: u64,
 ) -> Instruction {
     // Build the instruction.
-    let queue_pubkey = Queue::pubkey(queue.authority, queue.id);
+    let queue_pubkey = Queue::pubkey(queue.creator, queue.id);
     let mut crank_ix = clockwork_client::queue::instruction::queue_crank(
         queue_pubkey,
         signatory_pubkey,
         Worker::pubkey(worker_id),
     );
 
-    if let Some(next_instruction) = queue.next_instruction {
+    for next_instruction in queue.next_instructions {
         // Inject the target program account to the ix.
         crank_ix.accounts.push(AccountMeta::new_readonly(
             next_instruction.program_id,
@@ -230,7 +519,7 @@ fn build_crank_ix(
         ));
 
         // Inject the worker pubkey as the Clockwork "payer" account
-        for acc in next_instruction.clone().accounts {
+        for acc in next_instruction.accounts {
             let acc_pubkey = if acc.pubkey == clockwork_utils::PAYER_PUBKEY {
                 signatory_pubkey
             } else {
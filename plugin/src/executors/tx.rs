@@ -7,8 +7,8 @@ use {
     solana_geyser_plugin_interface::geyser_plugin_interface::{
         GeyserPluginError, Result as PluginResult,
     },
-    solana_program::{hash::Hash, message::Message},
-    solana_sdk::{commitment_config::CommitmentConfig, transaction::Transaction},
+    solana_program::{hash::Hash, message::VersionedMessage},
+    solana_sdk::{commitment_config::CommitmentConfig, transaction::VersionedTransaction},
     std::{fmt::Debug, sync::Arc},
     tokio::runtime::Runtime,
 };
@@ -74,7 +74,10 @@ impl TxExecutor {
         ) {
             None => {}
             Some(tx) => {
-                self.clone().execute_tx(slot, &tx).map_err(|err| err).ok();
+                self.clone()
+                    .execute_tx(slot, &VersionedTransaction::from(tx))
+                    .map_err(|err| err)
+                    .ok();
             }
         };
         Ok(())
@@ -106,11 +109,11 @@ impl TxExecutor {
         Ok(())
     }
 
-    fn execute_tx(self: Arc<Self>, slot: u64, tx: &Transaction) -> PluginResult<()> {
+    fn execute_tx(self: Arc<Self>, slot: u64, tx: &VersionedTransaction) -> PluginResult<()> {
         // Exit early if this message was sent recently
         if let Some(entry) = self
             .message_history
-            .get(&tx.message().blockhash_agnostic_hash())
+            .get(&tx.message.blockhash_agnostic_hash())
         {
             let msg_slot = entry.value();
             if slot < msg_slot + MESSAGE_DEDUPE_PERIOD {
@@ -126,37 +129,40 @@ impl TxExecutor {
             .and_then(|tx| self.log_tx(slot, tx))
     }
 
-    fn _simulate_tx(self: Arc<Self>, tx: &Transaction) -> PluginResult<Transaction> {
+    fn _simulate_tx(
+        self: Arc<Self>,
+        tx: &VersionedTransaction,
+    ) -> PluginResult<VersionedTransaction> {
         // TODO Only submit this transaction if the simulated increase in this worker's
         //      Fee account balance is greater than the lamports spent by the worker.
 
-        self.tpu_client
-            .rpc_client()
-            .simulate_transaction_with_config(
-                tx,
-                RpcSimulateTransactionConfig {
-                    replace_recent_blockhash: true,
-                    commitment: Some(CommitmentConfig::processed()),
-                    ..RpcSimulateTransactionConfig::default()
-                },
-            )
-            .map_err(|err| {
-                GeyserPluginError::Custom(format!("Tx failed simulation: {}", err).into())
-            })
-            .map(|response| match response.value.err {
-                None => Ok(tx.clone()),
-                Some(err) => Err(GeyserPluginError::Custom(
-                    format!(
-                        "Tx failed simulation: {} Logs: {:#?}",
-                        err, response.value.logs
-                    )
-                    .into(),
-                )),
-            })?
+        crate::utils::simulate_versioned_transaction(
+            self.tpu_client.rpc_client(),
+            tx,
+            RpcSimulateTransactionConfig {
+                replace_recent_blockhash: true,
+                commitment: Some(CommitmentConfig::processed()),
+                ..RpcSimulateTransactionConfig::default()
+            },
+        )
+        .map_err(|err| GeyserPluginError::Custom(format!("Tx failed simulation: {}", err).into()))
+        .map(|response| match response.value.err {
+            None => Ok(tx.clone()),
+            Some(err) => Err(GeyserPluginError::Custom(
+                format!(
+                    "Tx failed simulation: {} Logs: {:#?}",
+                    err, response.value.logs
+                )
+                .into(),
+            )),
+        })?
     }
 
-    fn submit_tx(self: Arc<Self>, tx: &Transaction) -> PluginResult<Transaction> {
-        if !self.tpu_client.send_transaction(tx) {
+    fn submit_tx(self: Arc<Self>, tx: &VersionedTransaction) -> PluginResult<VersionedTransaction> {
+        let wire_transaction = bincode::serialize(tx).map_err(|err| {
+            GeyserPluginError::Custom(format!("Failed to serialize transaction: {}", err).into())
+        })?;
+        if !self.tpu_client.send_wire_transaction(&wire_transaction) {
             return Err(GeyserPluginError::Custom(
                 "Failed to send transaction".into(),
             ));
@@ -164,9 +170,9 @@ impl TxExecutor {
         Ok(tx.clone())
     }
 
-    fn log_tx(self: Arc<Self>, slot: u64, tx: Transaction) -> PluginResult<()> {
+    fn log_tx(self: Arc<Self>, slot: u64, tx: VersionedTransaction) -> PluginResult<()> {
         self.message_history
-            .insert(tx.message().blockhash_agnostic_hash(), slot);
+            .insert(tx.message.blockhash_agnostic_hash(), slot);
         let sig = tx.signatures[0];
         info!("slot: {} sig: {}", slot, sig);
         Ok(())
@@ -194,14 +200,13 @@ trait BlockhashAgnosticHash {
     fn blockhash_agnostic_hash(&self) -> Hash;
 }
 
-impl BlockhashAgnosticHash for Message {
+impl BlockhashAgnosticHash for VersionedMessage {
     fn blockhash_agnostic_hash(&self) -> Hash {
-        Message {
-            header: self.header.clone(),
-            account_keys: self.account_keys.clone(),
-            recent_blockhash: Hash::default(),
-            instructions: self.instructions.clone(),
+        let mut message = self.clone();
+        match &mut message {
+            VersionedMessage::Legacy(message) => message.recent_blockhash = Hash::default(),
+            VersionedMessage::V0(message) => message.recent_blockhash = Hash::default(),
         }
-        .hash()
+        message.hash()
     }
 }
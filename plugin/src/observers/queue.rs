@@ -2,13 +2,20 @@ use {
     crate::config::PluginConfig,
     chrono::{DateTime, NaiveDateTime, Utc},
     clockwork_client::queue::objects::{Queue, Trigger, TriggerContext},
-    clockwork_cron::Schedule,
+    clockwork_cron::{error::Error as CronError, Schedule, Tz},
     dashmap::{DashMap, DashSet},
+    log::info,
     solana_geyser_plugin_interface::geyser_plugin_interface::{
         GeyserPluginError, ReplicaAccountInfo, Result as PluginResult,
     },
     solana_program::{clock::Clock, pubkey::Pubkey},
-    std::{fmt::Debug, str::FromStr, sync::Arc},
+    std::{
+        collections::hash_map::DefaultHasher,
+        fmt::Debug,
+        hash::{Hash, Hasher},
+        str::FromStr,
+        sync::Arc,
+    },
     tokio::runtime::Runtime,
 };
 
@@ -16,31 +23,63 @@ pub struct QueueObserver {
     // Map from slot numbers to the sysvar clock data for that slot.
     pub clocks: DashMap<u64, Clock>,
 
+    // Map from condition-triggered queue pubkeys to whether they are armed to fire the next time their condition holds.
+    pub condition_armed: DashMap<Pubkey, bool>,
+
     // Plugin config values.
     pub config: PluginConfig,
 
-    // The set of the queues that are currently crankable (i.e. have a next_instruction)
+    // The set of the queues that are currently crankable (i.e. have next_instructions)
     pub crankable_queues: DashSet<Pubkey>,
 
     // Map from unix timestamps to the list of queues scheduled for that moment.
     pub cron_queues: DashMap<i64, DashSet<Pubkey>>,
 
+    // Map from dependent queue pubkeys to the slot of the upstream run they last ran after.
+    pub dependent_completions: DashMap<Pubkey, u64>,
+
+    // Map from queue pubkeys to the set of queues waiting for them to complete a run.
+    pub dependent_queues: DashMap<Pubkey, DashSet<Pubkey>>,
+
+    // Map from epochs to the list of queues scheduled for the start of that epoch.
+    pub epoch_queues: DashMap<u64, DashSet<Pubkey>>,
+
+    // Map from queue pubkeys to a hash of the account data range they last observed.
+    pub listener_data_hashes: DashMap<Pubkey, u64>,
+
     // Map from account pubkeys to the set of queues listening for an account update.
     pub listener_queues: DashMap<Pubkey, DashSet<Pubkey>>,
 
+    // Map from queue pubkeys to the account-based triggers they are listening with.
+    pub listener_triggers: DashMap<Pubkey, Trigger>,
+
+    // Map from queue pubkeys to the slot at which they last completed a run.
+    pub queue_completions: DashMap<Pubkey, u64>,
+
     // Tokio runtime for processing async tasks.
     pub runtime: Arc<Runtime>,
+
+    // Map from slot numbers to the list of queues scheduled for that slot.
+    pub slot_queues: DashMap<u64, DashSet<Pubkey>>,
 }
 
 impl QueueObserver {
     pub fn new(config: PluginConfig, runtime: Arc<Runtime>) -> Self {
         Self {
             clocks: DashMap::new(),
+            condition_armed: DashMap::new(),
             config: config.clone(),
             crankable_queues: DashSet::new(),
             cron_queues: DashMap::new(),
+            dependent_completions: DashMap::new(),
+            dependent_queues: DashMap::new(),
+            epoch_queues: DashMap::new(),
+            listener_data_hashes: DashMap::new(),
             listener_queues: DashMap::new(),
+            listener_triggers: DashMap::new(),
+            queue_completions: DashMap::new(),
             runtime,
+            slot_queues: DashMap::new(),
         }
     }
 
@@ -49,6 +88,17 @@ impl QueueObserver {
             this.clocks
                 .retain(|cached_slot, _clock| *cached_slot >= slot);
 
+            // Index all of the slot-triggered queues that are now due.
+            this.slot_queues.retain(|target_slot, queue_pubkeys| {
+                let is_due = slot >= *target_slot;
+                if is_due {
+                    for queue_pubkey_ref in queue_pubkeys.iter() {
+                        this.crankable_queues.insert(*queue_pubkey_ref.key());
+                    }
+                }
+                !is_due
+            });
+
             // Get the clock for this slot.
             match this.clocks.get(&slot) {
                 None => {}
@@ -64,6 +114,17 @@ impl QueueObserver {
                         }
                         !is_due
                     });
+
+                    // Index all of the epoch-triggered queues that are now due.
+                    this.epoch_queues.retain(|target_epoch, queue_pubkeys| {
+                        let is_due = clock.epoch >= *target_epoch;
+                        if is_due {
+                            for queue_pubkey_ref in queue_pubkeys.iter() {
+                                this.crankable_queues.insert(*queue_pubkey_ref.key());
+                            }
+                        }
+                        !is_due
+                    });
                 }
             };
 
@@ -81,23 +142,83 @@ impl QueueObserver {
     pub fn observe_account(
         self: Arc<Self>,
         account_pubkey: Pubkey,
-        _account_replica: ReplicaAccountInfo,
+        account_replica: ReplicaAccountInfo,
     ) -> PluginResult<()> {
+        // Exit early if no queues are listening to this account.
+        if !self.listener_queues.contains_key(&account_pubkey) {
+            return Ok(());
+        }
+        let account_data = account_replica.data.to_vec();
+
         self.spawn(|this| async move {
-            // Move all queues listening to this account into the crankable set.
+            // Move all queues whose watched byte range has changed into the crankable set.
             this.listener_queues.retain(|pubkey, queue_pubkeys| {
-                if account_pubkey.eq(pubkey) {
-                    for queue_pubkey in queue_pubkeys.iter() {
-                        this.crankable_queues.insert(*queue_pubkey.key());
-                    }
-                    false
-                } else {
-                    true
+                if !account_pubkey.eq(pubkey) {
+                    return true;
                 }
-            });
+                queue_pubkeys.retain(|queue_pubkey| {
+                    let trigger = match this.listener_triggers.get(queue_pubkey) {
+                        None => return true,
+                        Some(trigger) => trigger.value().clone(),
+                    };
 
-            // TODO This account update could have just been a lamport change (not a data update).
-            // TODO To optimize, we need to fetch the queue accounts to verify the data update
+                    // Hash the byte range this queue is watching.
+                    let range_data = match trigger {
+                        Trigger::Account { .. } | Trigger::AccountRange { .. } => {
+                            trigger.observed_data(&account_data)
+                        }
+                        Trigger::Condition {
+                            offset, value_type, ..
+                        } => account_data
+                            .get(offset as usize..)
+                            .and_then(|data| data.get(..value_type.size())),
+                        _ => return true,
+                    };
+                    let range_data = match range_data {
+                        None => return true,
+                        Some(range_data) => range_data,
+                    };
+                    let mut hasher = DefaultHasher::new();
+                    range_data.hash(&mut hasher);
+                    let data_hash = hasher.finish();
+
+                    // If the range has changed since it was last observed (e.g. this was not just a lamport change),
+                    // the queue is crankable. Condition triggers additionally require the condition to start holding
+                    // while the queue is armed, or to stop holding while it is not, so the queue can be armed.
+                    let is_changed =
+                        match this.listener_data_hashes.insert(*queue_pubkey, data_hash) {
+                            None => true,
+                            Some(prior_data_hash) => prior_data_hash.ne(&data_hash),
+                        };
+                    let is_active = is_changed
+                        && match trigger {
+                            Trigger::Condition {
+                                value_type,
+                                endianness,
+                                operator,
+                                operand,
+                                ..
+                            } => {
+                                let armed = this
+                                    .condition_armed
+                                    .get(queue_pubkey)
+                                    .map_or(true, |armed| *armed.value());
+                                value_type
+                                    .read(range_data, endianness)
+                                    .map_or(false, |value| {
+                                        operator.evaluate(value_type.compare(value, operand))
+                                            == armed
+                                    })
+                            }
+                            _ => true,
+                        };
+                    if is_active {
+                        this.crankable_queues.insert(*queue_pubkey);
+                    }
+                    !is_active
+                });
+                !queue_pubkeys.is_empty()
+            });
 
             Ok(())
         })
@@ -108,21 +229,63 @@ impl QueueObserver {
             // Remove queue from crankable set
             this.crankable_queues.remove(&queue_pubkey);
 
+            // If the queue has completed a new run, move the queues waiting on it into the crankable set.
+            if queue.next_instructions.is_empty() {
+                if let Some(exec_context) = queue.exec_context {
+                    let completed_at = exec_context.last_crank_at;
+                    this.queue_completions.insert(queue_pubkey, completed_at);
+                    if let Some(dependent_pubkeys) = this.dependent_queues.get(&queue_pubkey) {
+                        dependent_pubkeys.retain(|dependent_pubkey| {
+                            let is_due = this
+                                .dependent_completions
+                                .get(dependent_pubkey)
+                                .map_or(true, |prior_completed_at| {
+                                    completed_at > *prior_completed_at.value()
+                                });
+                            if is_due {
+                                this.crankable_queues.insert(*dependent_pubkey);
+                            }
+                            !is_due
+                        });
+                    }
+                }
+            }
+
             // If the queue is paused, just return without indexing
             if queue.paused {
                 return Ok(());
             }
 
-            if queue.next_instruction.is_some() {
-                // If the queue has a next instruction, index it as crankable.
+            if !queue.next_instructions.is_empty() {
+                // If the queue has next instructions, index it as crankable.
                 this.crankable_queues.insert(queue_pubkey);
             } else {
                 // Otherwise, index the queue according to its trigger type.
-                match queue.trigger {
+                match queue.trigger.clone() {
                     Trigger::Account {
                         pubkey: account_pubkey,
+                    }
+                    | Trigger::AccountRange {
+                        pubkey: account_pubkey,
+                        ..
+                    }
+                    | Trigger::Condition {
+                        pubkey: account_pubkey,
+                        ..
                     } => {
                         // Index the queue by its trigger's account pubkey.
+                        this.listener_triggers
+                            .insert(queue_pubkey, queue.trigger.clone());
+                        if let Trigger::Condition { .. } = queue.trigger {
+                            let armed = match queue.exec_context {
+                                None => true,
+                                Some(exec_context) => matches!(
+                                    exec_context.trigger_context,
+                                    TriggerContext::Condition { armed: true, .. }
+                                ),
+                            };
+                            this.condition_armed.insert(queue_pubkey, armed);
+                        }
                         this.listener_queues
                             .entry(account_pubkey)
                             .and_modify(|v| {
@@ -137,12 +300,20 @@ impl QueueObserver {
                     Trigger::Cron {
                         schedule,
                         skippable: _,
+                        timezone,
+                        start_at,
+                        end_at,
+                        max_runs,
                     } => {
-                        // Find a reference timestamp for calculating the queue's upcoming target time.
-                        let reference_timestamp = match queue.exec_context {
-                            None => queue.created_at.unix_timestamp,
+                        // Find a reference timestamp for calculating the queue's upcoming target time,
+                        // and the number of times the schedule has already kicked off the queue.
+                        let (reference_timestamp, runs) = match queue.exec_context {
+                            None => (queue.created_at.unix_timestamp, 0),
                             Some(exec_context) => match exec_context.trigger_context {
-                                TriggerContext::Cron { started_at } => started_at,
+                                TriggerContext::Cron { started_at } => (started_at, 0),
+                                TriggerContext::BoundedCron { started_at, runs } => {
+                                    (started_at, runs)
+                                }
                                 _ => {
                                     return Err(GeyserPluginError::Custom(
                                         "Invalid exec context".into(),
@@ -151,10 +322,27 @@ impl QueueObserver {
                             },
                         };
 
-                        // Index the queue to its target timestamp
-                        match next_moment(reference_timestamp, schedule) {
-                            None => {} // The queue does not have any upcoming scheduled target time
-                            Some(target_timestamp) => {
+                        // Stop tracking the queue once the schedule has exhausted its maximum number of runs.
+                        if max_runs.map_or(false, |max_runs| runs >= max_runs) {
+                            return Ok(());
+                        }
+
+                        // The schedule's first moment may be no earlier than its start timestamp.
+                        let reference_timestamp = match start_at {
+                            None => reference_timestamp,
+                            Some(start_at) => reference_timestamp.max(start_at.saturating_sub(1)),
+                        };
+
+                        // Index the queue to its target timestamp, unless it is past the schedule's end timestamp.
+                        match next_moment(reference_timestamp, schedule, timezone) {
+                            Err(err) => info!(
+                                "Skipping queue {} with an invalid cron trigger: {}",
+                                queue_pubkey, err
+                            ),
+                            Ok(None) => {} // The queue does not have any upcoming scheduled target time
+                            Ok(Some(target_timestamp))
+                                if end_at.map_or(false, |end_at| target_timestamp > end_at) => {}
+                            Ok(Some(target_timestamp)) => {
                                 this.cron_queues
                                     .entry(target_timestamp)
                                     .and_modify(|v| {
@@ -171,6 +359,143 @@ impl QueueObserver {
                     Trigger::Immediate => {
                         this.crankable_queues.insert(queue_pubkey);
                     }
+                    Trigger::Slot { slot } => {
+                        // Slot triggers only fire once. Skip the queue if it has already been kicked off.
+                        if queue.exec_context.is_none() {
+                            // Index the queue to its target slot.
+                            this.slot_queues
+                                .entry(slot)
+                                .and_modify(|v| {
+                                    v.insert(queue_pubkey);
+                                })
+                                .or_insert_with(|| {
+                                    let v = DashSet::new();
+                                    v.insert(queue_pubkey);
+                                    v
+                                });
+                        }
+                    }
+                    Trigger::Epoch { epoch } => {
+                        // Find the next epoch this queue may be kicked off in.
+                        let target_epoch = match queue.exec_context {
+                            None => epoch,
+                            Some(exec_context) => match exec_context.trigger_context {
+                                TriggerContext::Epoch { started_at } => {
+                                    epoch.max(started_at.checked_add(1).unwrap())
+                                }
+                                _ => {
+                                    return Err(GeyserPluginError::Custom(
+                                        "Invalid exec context".into(),
+                                    ))
+                                }
+                            },
+                        };
+
+                        // Index the queue to its target epoch.
+                        this.epoch_queues
+                            .entry(target_epoch)
+                            .and_modify(|v| {
+                                v.insert(queue_pubkey);
+                            })
+                            .or_insert_with(|| {
+                                let v = DashSet::new();
+                                v.insert(queue_pubkey);
+                                v
+                            });
+                    }
+                    Trigger::Queue {
+                        pubkey: upstream_pubkey,
+                    } => {
+                        // Track the upstream run this queue last ran after.
+                        let prior_completed_at = match queue
+                            .exec_context
+                            .map(|exec_context| exec_context.trigger_context)
+                        {
+                            Some(TriggerContext::Queue { completed_at }) => {
+                                this.dependent_completions
+                                    .insert(queue_pubkey, completed_at);
+                                Some(completed_at)
+                            }
+                            _ => {
+                                this.dependent_completions.remove(&queue_pubkey);
+                                None
+                            }
+                        };
+
+                        // If the upstream queue has already completed a new run (e.g. before the plugin started),
+                        // the queue is due now. Otherwise, index it by its upstream queue's pubkey.
+                        let is_due = this.queue_completions.get(&upstream_pubkey).map_or(
+                            false,
+                            |completed_at| {
+                                prior_completed_at.map_or(true, |prior_completed_at| {
+                                    *completed_at.value() > prior_completed_at
+                                })
+                            },
+                        );
+                        if is_due {
+                            this.crankable_queues.insert(queue_pubkey);
+                        } else {
+                            this.dependent_queues
+                                .entry(upstream_pubkey)
+                                .and_modify(|v| {
+                                    v.insert(queue_pubkey);
+                                })
+                                .or_insert_with(|| {
+                                    let v = DashSet::new();
+                                    v.insert(queue_pubkey);
+                                    v
+                                });
+                        }
+                    }
+                    Trigger::Interval {
+                        seconds,
+                        skippable: _,
+                    } => {
+                        // Find a reference timestamp for calculating the queue's upcoming target time.
+                        let reference_timestamp = match queue.exec_context {
+                            None => queue.created_at.unix_timestamp,
+                            Some(exec_context) => match exec_context.trigger_context {
+                                TriggerContext::Interval { started_at } => started_at,
+                                _ => {
+                                    return Err(GeyserPluginError::Custom(
+                                        "Invalid exec context".into(),
+                                    ))
+                                }
+                            },
+                        };
+
+                        // Index the queue to its target timestamp.
+                        if let Some(target_timestamp) =
+                            reference_timestamp.checked_add(seconds as i64)
+                        {
+                            this.cron_queues
+                                .entry(target_timestamp)
+                                .and_modify(|v| {
+                                    v.insert(queue_pubkey);
+                                })
+                                .or_insert_with(|| {
+                                    let v = DashSet::new();
+                                    v.insert(queue_pubkey);
+                                    v
+                                });
+                        }
+                    }
+                    Trigger::Timestamp { unix_ts } => {
+                        // Timestamp triggers only fire once. Skip the queue if it has already been kicked off.
+                        if queue.exec_context.is_none() {
+                            // Index the queue to its target timestamp.
+                            this.cron_queues
+                                .entry(unix_ts)
+                                .and_modify(|v| {
+                                    v.insert(queue_pubkey);
+                                })
+                                .or_insert_with(|| {
+                                    let v = DashSet::new();
+                                    v.insert(queue_pubkey);
+                                    v
+                                });
+                        }
+                    }
                 }
             }
 
@@ -193,13 +518,22 @@ impl Debug for QueueObserver {
     }
 }
 
-fn next_moment(after: i64, schedule: String) -> Option<i64> {
-    Schedule::from_str(&schedule)
-        .unwrap()
-        .next_after(&DateTime::<Utc>::from_utc(
-            NaiveDateTime::from_timestamp(after, 0),
-            Utc,
-        ))
-        .take()
-        .map(|datetime| datetime.timestamp())
+fn next_moment(
+    after: i64,
+    schedule: String,
+    timezone: Option<String>,
+) -> Result<Option<i64>, CronError> {
+    let schedule = Schedule::from_str(&schedule)?;
+    let after = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(after, 0), Utc);
+    Ok(match timezone {
+        None => schedule
+            .next_after(&after)
+            .map(|datetime| datetime.timestamp()),
+        Some(timezone) => {
+            let timezone = Tz::from_str(&timezone)?;
+            schedule
+                .next_after(&after.with_timezone(&timezone))
+                .map(|datetime| datetime.timestamp())
+        }
+    })
 }
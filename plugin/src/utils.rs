@@ -1,4 +1,18 @@
-use solana_sdk::signature::{read_keypair_file, Keypair};
+use {
+    serde_json::json,
+    solana_client::{
+        client_error::{ClientError, ClientErrorKind, Result as ClientResult},
+        rpc_client::RpcClient,
+        rpc_config::RpcSimulateTransactionConfig,
+        rpc_request::RpcRequest,
+        rpc_response::{Response, RpcSimulateTransactionResult},
+    },
+    solana_sdk::{
+        signature::{read_keypair_file, Keypair},
+        transaction::VersionedTransaction,
+    },
+    solana_transaction_status::UiTransactionEncoding,
+};
 
 pub fn read_or_new_keypair(keypath: Option<String>) -> Keypair {
     match keypath {
@@ -6,3 +20,23 @@ pub fn read_or_new_keypair(keypath: Option<String>) -> Keypair {
         None => Keypair::new(),
     }
 }
+
+/// Simulate a (possibly v0) versioned transaction against the given rpc client.
+pub fn simulate_versioned_transaction(
+    client: &RpcClient,
+    tx: &VersionedTransaction,
+    config: RpcSimulateTransactionConfig,
+) -> ClientResult<Response<RpcSimulateTransactionResult>> {
+    let serialized_tx = bincode::serialize(tx)
+        .map_err(|err| ClientError::from(ClientErrorKind::Custom(err.to_string())))?;
+    client.send(
+        RpcRequest::SimulateTransaction,
+        json!([
+            base64::encode(serialized_tx),
+            RpcSimulateTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                ..config
+            }
+        ]),
+    )
+}
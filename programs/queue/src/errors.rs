@@ -39,4 +39,68 @@ pub enum ClockworkError {
     /// Thrown if an inner instruction attempted to write to an unauthorized address.
     #[msg("Inner instruction attempted to write to an unauthorized address")]
     UnauthorizedWrite,
+
+    /// Thrown if an account trigger's byte range extends beyond the end of the account's data.
+    #[msg("The trigger's byte range exceeds the size of the account data")]
+    RangeOutOfBounds,
+
+    /// Thrown if a queue is given an empty list of kickoff instructions.
+    #[msg("Queues must have at least one kickoff instruction")]
+    InvalidKickoffInstructions,
+
+    /// Thrown if a queue authority attempts to set an exec history limit above the maximum allowed value.
+    #[msg("Queue exec history limits cannot exceed the maximum allowed value")]
+    MaxExecHistoryLimitExceeded,
+
+    /// Thrown if a queue's lamport balance cannot cover the fees and reimbursements owed by a crank.
+    #[msg("The queue's balance is insufficient to pay for the crank")]
+    InsufficientQueueBalance,
+
+    /// Thrown if a cron trigger is given a time zone which is not in the `clockwork_cron` time zone table.
+    #[msg("The cron trigger's time zone is not supported")]
+    InvalidTimezone,
+
+    /// Thrown if a cron trigger is given a schedule which cannot be parsed by the `clockwork_cron` package.
+    #[msg("The cron trigger's schedule is invalid")]
+    InvalidSchedule,
+
+    /// Thrown if an interval trigger is given an interval of zero seconds.
+    #[msg("The interval trigger's interval must be greater than zero")]
+    InvalidInterval,
+
+    /// Thrown if a cron trigger's end timestamp precedes its start timestamp, or its maximum number of runs is zero.
+    #[msg("The cron trigger's bounds are invalid")]
+    InvalidScheduleBounds,
+
+    /// Thrown if a queue is kicked off after its cron trigger has passed its end timestamp or maximum number of runs.
+    #[msg("The trigger has expired")]
+    TriggerExpired,
+
+    /// Thrown if a queue authority attempts to set a compute unit limit of zero or above the maximum allowed by the runtime.
+    #[msg("The compute unit limit must be greater than zero and cannot exceed 1,400,000")]
+    InvalidComputeUnitLimit,
+
+    /// Thrown if a signer is neither the queue's authority nor a delegate with the role required by the instruction.
+    #[msg("The signer is not authorized to perform this action on the queue")]
+    Unauthorized,
+
+    /// Thrown if a queue authority attempts to grant roles to more delegates than the maximum allowed.
+    #[msg("Queues cannot have more than the maximum number of delegates")]
+    MaxDelegatesExceeded,
+
+    /// Thrown if an account attempts to accept ownership of a queue which was not offered to it.
+    #[msg("The signer is not the pending authority of the queue")]
+    NotPendingAuthority,
+
+    /// Thrown if an account trigger's byte range is empty or extends beyond the maximum size of an account.
+    #[msg("The trigger's byte range is invalid")]
+    InvalidAccountRange,
+
+    /// Thrown if an account being migrated is not a queue in a legacy layout.
+    #[msg("The account is not a queue in a legacy layout")]
+    NotLegacyQueue,
+
+    /// Thrown if the priority fee to reimburse a worker with cannot be represented in lamports.
+    #[msg("The transaction's priority fee is too large to reimburse")]
+    InvalidPriorityFee,
 }
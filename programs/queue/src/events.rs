@@ -0,0 +1,143 @@
+//! Events emitted by the program.
+
+use {crate::objects::Role, anchor_lang::prelude::*};
+
+/// Emitted when a queue is created.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueCreated {
+    /// The owner of the queue.
+    pub authority: Pubkey,
+    /// The id of the queue, given by the authority.
+    pub id: String,
+    /// The queue which was created.
+    pub queue: Pubkey,
+}
+
+/// Emitted when a queue's trigger condition is met and a new execution begins.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueKickedOff {
+    /// The queue which was kicked off.
+    pub queue: Pubkey,
+    /// The worker who submitted the kickoff.
+    pub worker: Pubkey,
+}
+
+/// Emitted when a queue is cranked.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueCranked {
+    /// The queue which was cranked.
+    pub queue: Pubkey,
+    /// The worker who submitted the crank.
+    pub worker: Pubkey,
+    /// The number of lamports paid out of the queue for the crank, including reimbursements.
+    pub fees_paid: u64,
+    /// Whether the crank response replaced the queue's kickoff instructions.
+    pub kickoff_instructions_updated: bool,
+    /// The number of instructions to invoke on the next crank. If zero, the execution is complete.
+    pub next_instructions: u64,
+}
+
+/// Emitted when a queue is paused by its authority.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueuePaused {
+    /// The queue which was paused.
+    pub queue: Pubkey,
+}
+
+/// Emitted when a queue is automatically paused because its balance is too low to pay for a crank.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueAutoPaused {
+    /// The queue which was paused.
+    pub queue: Pubkey,
+    /// The lamport balance of the queue at the time it was paused.
+    pub balance: u64,
+    /// The lamport balance the queue required to process the crank.
+    pub required_balance: u64,
+}
+
+/// Emitted when a paused queue is resumed.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueResumed {
+    /// The queue which was resumed.
+    pub queue: Pubkey,
+}
+
+/// Emitted when a queue's current execution is stopped.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueStopped {
+    /// The queue which was stopped.
+    pub queue: Pubkey,
+}
+
+/// Emitted when a queue authority grants a role to a delegate.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueDelegateGranted {
+    /// The queue the role was granted on.
+    pub queue: Pubkey,
+    /// The delegate who was granted the role.
+    pub delegate: Pubkey,
+    /// The role which was granted.
+    pub role: Role,
+}
+
+/// Emitted when a queue authority revokes a role from a delegate.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueDelegateRevoked {
+    /// The queue the role was revoked on.
+    pub queue: Pubkey,
+    /// The delegate whose role was revoked.
+    pub delegate: Pubkey,
+    /// The role which was revoked.
+    pub role: Role,
+}
+
+/// Emitted when a queue authority offers to transfer ownership of the queue to another account.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueAuthorityTransferStarted {
+    /// The queue whose ownership is being transferred.
+    pub queue: Pubkey,
+    /// The current authority of the queue.
+    pub authority: Pubkey,
+    /// The account which may accept ownership of the queue.
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when an account accepts ownership of a queue.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueAuthorityAccepted {
+    /// The queue whose ownership was transferred.
+    pub queue: Pubkey,
+    /// The previous authority of the queue.
+    pub old_authority: Pubkey,
+    /// The new authority of the queue.
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when a queue is deleted.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueDeleted {
+    /// The queue which was deleted.
+    pub queue: Pubkey,
+    /// The account which received the queue's remaining lamports.
+    pub close_to: Pubkey,
+}
+
+/// Emitted when a queue in a legacy layout is migrated to the current layout.
+#[event]
+#[derive(Clone, Debug)]
+pub struct QueueMigrated {
+    /// The queue which was migrated.
+    pub queue: Pubkey,
+}
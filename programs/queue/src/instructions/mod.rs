@@ -1,19 +1,31 @@
+pub mod queue_accept_authority;
 pub mod queue_crank;
 pub mod queue_create;
+pub mod queue_delegate_grant;
+pub mod queue_delegate_revoke;
 pub mod queue_delete;
+pub mod queue_fund;
 pub mod queue_kickoff;
+pub mod queue_migrate;
 pub mod queue_pause;
 pub mod queue_resume;
 pub mod queue_stop;
+pub mod queue_transfer_authority;
 pub mod queue_update;
 pub mod queue_withdraw;
 
+pub use queue_accept_authority::*;
 pub use queue_crank::*;
 pub use queue_create::*;
+pub use queue_delegate_grant::*;
+pub use queue_delegate_revoke::*;
 pub use queue_delete::*;
+pub use queue_fund::*;
 pub use queue_kickoff::*;
+pub use queue_migrate::*;
 pub use queue_pause::*;
 pub use queue_resume::*;
 pub use queue_stop::*;
+pub use queue_transfer_authority::*;
 pub use queue_update::*;
 pub use queue_withdraw::*;
@@ -0,0 +1,48 @@
+use {
+    crate::{errors::*, events::*, objects::*},
+    anchor_lang::prelude::*,
+};
+
+/// Accounts required by the `queue_accept_authority` instruction.
+#[derive(Accounts)]
+pub struct QueueAcceptAuthority<'info> {
+    /// The account the queue's ownership was offered to.
+    #[account()]
+    pub new_authority: Signer<'info>,
+
+    /// The queue to be transferred.
+    #[account(
+        mut,
+        seeds = [
+            SEED_QUEUE,
+            queue.creator.as_ref(),
+            queue.id.as_bytes(),
+        ],
+        bump,
+        constraint = queue.pending_authority.eq(&Some(new_authority.key())) @ ClockworkError::NotPendingAuthority,
+    )]
+    pub queue: Account<'info, Queue>,
+}
+
+pub fn handler(ctx: Context<QueueAcceptAuthority>) -> Result<()> {
+    // Get accounts
+    let new_authority = &ctx.accounts.new_authority;
+    let queue = &mut ctx.accounts.queue;
+
+    // Transfer ownership of the queue. Delegates were granted by the previous authority, so revoke them.
+    let old_authority = queue.authority;
+    queue.authority = new_authority.key();
+    queue.delegates = vec![];
+    queue.pending_authority = None;
+
+    // Reallocate mem for the queue account
+    queue.realloc()?;
+
+    emit!(QueueAuthorityAccepted {
+        queue: queue.key(),
+        old_authority,
+        new_authority: new_authority.key(),
+    });
+
+    Ok(())
+}
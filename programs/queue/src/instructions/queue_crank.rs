@@ -1,6 +1,6 @@
 use {
     crate::{errors::*, objects::*},
-    anchor_lang::prelude::*,
+    anchor_lang::{prelude::*, solana_program::sysvar},
     clockwork_network_program::objects::{Fee, Penalty, Pool, Worker, WorkerAccount},
 };
 
@@ -23,6 +23,11 @@ pub struct QueueCrank<'info> {
     )]
     pub fee: Account<'info, Fee>,
 
+    /// The instructions sysvar, to read the compute budget requested by the transaction.
+    /// CHECK: The address is verified by the account constraint.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
     /// The worker's penalty account.
     #[account(
         mut,
@@ -45,12 +50,12 @@ pub struct QueueCrank<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
         constraint = !queue.paused @ ClockworkError::QueuePaused,
-        constraint = queue.next_instruction.is_some()
+        constraint = !queue.next_instructions.is_empty()
     )]
     pub queue: Box<Account<'info, Queue>>,
 
@@ -69,6 +74,7 @@ pub struct QueueCrank<'info> {
 pub fn handler(ctx: Context<QueueCrank>) -> Result<()> {
     // Get accounts
     let fee = &mut ctx.accounts.fee;
+    let instructions = &ctx.accounts.instructions;
     let penalty = &mut ctx.accounts.penalty;
     let pool = &ctx.accounts.pool;
     let queue = &mut ctx.accounts.queue;
@@ -93,6 +99,7 @@ pub fn handler(ctx: Context<QueueCrank>) -> Result<()> {
         ctx.remaining_accounts,
         *bump,
         fee,
+        instructions,
         penalty,
         pool,
         signatory,
@@ -1,5 +1,5 @@
 use {
-    crate::objects::*,
+    crate::{events::*, objects::*},
     anchor_lang::{prelude::*, solana_program::system_program},
     clockwork_utils::*,
     std::mem::size_of,
@@ -8,7 +8,7 @@ use {
 
 /// Accounts required by the `queue_create` instruction.
 #[derive(Accounts)]
-#[instruction(id: String, kickoff_instruction: InstructionData, trigger: Trigger)]
+#[instruction(id: String, kickoff_instructions: Vec<InstructionData>, trigger: Trigger)]
 pub struct QueueCreate<'info> {
     /// The authority (owner) of the queue.
     #[account()]
@@ -32,7 +32,7 @@ pub struct QueueCreate<'info> {
             8, 
             size_of::<Queue>(), 
             id.as_bytes().len(),
-            kickoff_instruction.try_to_vec()?.len(),  
+            kickoff_instructions.try_to_vec()?.len(),  
             trigger.try_to_vec()?.len()
         ].iter().sum()
     )]
@@ -43,13 +43,19 @@ pub struct QueueCreate<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<QueueCreate>, id: String, kickoff_instruction: InstructionData, trigger: Trigger) -> Result<()> {
+pub fn handler(ctx: Context<QueueCreate>, id: String, kickoff_instructions: Vec<InstructionData>, trigger: Trigger) -> Result<()> {
     // Get accounts
     let authority = &ctx.accounts.authority;
     let queue = &mut ctx.accounts.queue;
 
     // Initialize the queue
-    queue.init(authority.key(), id, kickoff_instruction, trigger)?;
+    queue.init(authority.key(), id, kickoff_instructions, trigger)?;
+
+    emit!(QueueCreated {
+        authority: authority.key(),
+        id: queue.id.clone(),
+        queue: queue.key(),
+    });
 
     Ok(())
 }
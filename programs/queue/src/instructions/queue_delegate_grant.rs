@@ -0,0 +1,79 @@
+use {
+    crate::{errors::*, events::*, objects::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+    },
+};
+
+/// Accounts required by the `queue_delegate_grant` instruction.
+#[derive(Accounts)]
+#[instruction(delegate: Delegate)]
+pub struct QueueDelegateGrant<'info> {
+    /// The authority (owner) of the queue.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The queue to grant the role on.
+    #[account(
+        mut,
+        seeds = [
+            SEED_QUEUE,
+            queue.creator.as_ref(),
+            queue.id.as_bytes(),
+        ],
+        bump,
+        has_one = authority,
+    )]
+    pub queue: Account<'info, Queue>,
+
+    /// The Solana system program
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueDelegateGrant>, delegate: Delegate) -> Result<()> {
+    // Get accounts
+    let authority = &ctx.accounts.authority;
+    let queue = &mut ctx.accounts.queue;
+    let system_program = &ctx.accounts.system_program;
+
+    // Grant the role, if the delegate does not already hold it.
+    if !queue.delegates.contains(&delegate) {
+        require!(
+            queue.delegates.len() < MAX_DELEGATES,
+            ClockworkError::MaxDelegatesExceeded
+        );
+        queue.delegates.push(delegate);
+    }
+
+    // Reallocate mem for the queue account
+    queue.realloc()?;
+
+    // If lamports are required to maintain rent-exemption, pay them
+    let data_len = 8 + queue.try_to_vec()?.len();
+    let minimum_rent = Rent::get().unwrap().minimum_balance(data_len);
+    if minimum_rent > queue.to_account_info().lamports() {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: authority.to_account_info(),
+                    to: queue.to_account_info(),
+                },
+            ),
+            minimum_rent
+                .checked_sub(queue.to_account_info().lamports())
+                .unwrap(),
+        )?;
+    }
+
+    emit!(QueueDelegateGranted {
+        queue: queue.key(),
+        delegate: delegate.pubkey,
+        role: delegate.role,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use {
+    crate::{events::*, objects::*},
+    anchor_lang::prelude::*,
+};
+
+/// Accounts required by the `queue_delegate_revoke` instruction.
+#[derive(Accounts)]
+#[instruction(delegate: Delegate)]
+pub struct QueueDelegateRevoke<'info> {
+    /// The authority (owner) of the queue.
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The queue to revoke the role on.
+    #[account(
+        mut,
+        seeds = [
+            SEED_QUEUE,
+            queue.creator.as_ref(),
+            queue.id.as_bytes(),
+        ],
+        bump,
+        has_one = authority,
+    )]
+    pub queue: Account<'info, Queue>,
+}
+
+pub fn handler(ctx: Context<QueueDelegateRevoke>, delegate: Delegate) -> Result<()> {
+    // Get accounts
+    let queue = &mut ctx.accounts.queue;
+
+    // Revoke the role
+    queue.delegates.retain(|d| d.ne(&delegate));
+
+    // Reallocate mem for the queue account
+    queue.realloc()?;
+
+    emit!(QueueDelegateRevoked {
+        queue: queue.key(),
+        delegate: delegate.pubkey,
+        role: delegate.role,
+    });
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use {crate::objects::*, anchor_lang::prelude::*};
+use {
+    crate::{events::*, objects::*},
+    anchor_lang::prelude::*,
+};
 
 /// Accounts required by the `queue_delete` instruction.
 #[derive(Accounts)]
@@ -16,7 +19,7 @@ pub struct QueueDelete<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
@@ -26,6 +29,15 @@ pub struct QueueDelete<'info> {
     pub queue: Account<'info, Queue>,
 }
 
-pub fn handler(_ctx: Context<QueueDelete>) -> Result<()> {
+pub fn handler(ctx: Context<QueueDelete>) -> Result<()> {
+    // Get accounts
+    let close_to = &ctx.accounts.close_to;
+    let queue = &ctx.accounts.queue;
+
+    emit!(QueueDeleted {
+        queue: queue.key(),
+        close_to: close_to.key(),
+    });
+
     Ok(())
 }
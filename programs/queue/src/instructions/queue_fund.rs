@@ -0,0 +1,54 @@
+use {
+    crate::objects::*,
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+    },
+};
+
+/// Accounts required by the `queue_fund` instruction.
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct QueueFund<'info> {
+    /// The account paying lamports into the queue.
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// The queue to be funded.
+    #[account(
+        mut,
+        seeds = [
+            SEED_QUEUE,
+            queue.creator.as_ref(),
+            queue.id.as_bytes(),
+        ],
+        bump,
+    )]
+    pub queue: Account<'info, Queue>,
+
+    /// The Solana system program
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueFund>, amount: u64) -> Result<()> {
+    // Get accounts
+    let funder = &ctx.accounts.funder;
+    let queue = &mut ctx.accounts.queue;
+    let system_program = &ctx.accounts.system_program;
+
+    // Transfer lamports from the funder to the queue
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: funder.to_account_info(),
+                to: queue.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -13,12 +13,12 @@ pub struct QueueKickoff<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
         constraint = !queue.paused @ ClockworkError::QueuePaused,
-        constraint = queue.next_instruction.is_none() @ ClockworkError::QueueBusy,
+        constraint = queue.next_instructions.is_empty() @ ClockworkError::QueueBusy,
     )]
     pub queue: Box<Account<'info, Queue>>,
 
@@ -37,9 +37,10 @@ pub struct QueueKickoff<'info> {
 pub fn handler(ctx: Context<QueueKickoff>, data_hash: Option<u64>) -> Result<()> {
     // Get accounts.
     let queue = &mut ctx.accounts.queue;
+    let worker = &ctx.accounts.worker;
 
-    // If this queue does not have a next_instruction, verify the queue's trigger condition is active.
-    queue.kickoff(data_hash, ctx.remaining_accounts)?;
+    // If this queue does not have any next_instructions, verify the queue's trigger condition is active.
+    queue.kickoff(data_hash, ctx.remaining_accounts, worker)?;
 
     Ok(())
 }
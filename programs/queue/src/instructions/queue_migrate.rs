@@ -0,0 +1,83 @@
+use {
+    crate::{errors::*, events::*, objects::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+        AccountSerialize, Discriminator,
+    },
+};
+
+/// Accounts required by the `queue_migrate` instruction.
+#[derive(Accounts)]
+pub struct QueueMigrate<'info> {
+    /// The payer for the queue's growth in rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The queue to be migrated.
+    /// CHECK: Queues in a legacy layout cannot be deserialized as a `Queue`, so the account is verified by the handler.
+    #[account(mut, owner = crate::ID)]
+    pub queue: UncheckedAccount<'info>,
+
+    /// The Solana system program
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueMigrate>) -> Result<()> {
+    // Get accounts
+    let payer = &ctx.accounts.payer;
+    let queue = &ctx.accounts.queue;
+    let system_program = &ctx.accounts.system_program;
+
+    // Verify the queue is in the legacy layout, and read it.
+    let queue_v1 = {
+        let data = queue.try_borrow_data()?;
+        require!(
+            data.starts_with(&Queue::discriminator())
+                && Queue::try_deserialize(&mut data.as_ref()).is_err(),
+            ClockworkError::NotLegacyQueue
+        );
+        QueueV1::deserialize(&mut &data[8..]).map_err(|_err| ClockworkError::NotLegacyQueue)?
+    };
+
+    // Verify the queue's address.
+    require!(
+        queue
+            .key()
+            .eq(&Queue::pubkey(queue_v1.authority, queue_v1.id.clone())),
+        ClockworkError::NotLegacyQueue
+    );
+
+    // Convert the queue to the current layout.
+    let mut data = vec![];
+    Queue::from(queue_v1).try_serialize(&mut data)?;
+
+    // Reallocate mem for the queue account
+    queue.to_account_info().realloc(data.len(), false)?;
+
+    // If lamports are required to maintain rent-exemption, pay them
+    let minimum_rent = Rent::get().unwrap().minimum_balance(data.len());
+    if minimum_rent > queue.to_account_info().lamports() {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: queue.to_account_info(),
+                },
+            ),
+            minimum_rent
+                .checked_sub(queue.to_account_info().lamports())
+                .unwrap(),
+        )?;
+    }
+
+    // Write the queue in the current layout.
+    queue.try_borrow_mut_data()?.copy_from_slice(&data);
+
+    emit!(QueueMigrated { queue: queue.key() });
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
-use {crate::objects::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::*, events::*, objects::*},
+    anchor_lang::prelude::*,
+};
 
 /// Accounts required by the `queue_delete` instruction.
 #[derive(Accounts)]
 pub struct QueuePause<'info> {
-    /// The authority (owner) of the queue.
+    /// The authority (owner) of the queue, or a delegate with permission to pause it.
     #[account()]
     pub authority: Signer<'info>,
 
@@ -12,11 +15,11 @@ pub struct QueuePause<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
-        has_one = authority
+        constraint = queue.has_role(&authority.key(), Role::Pauser) @ ClockworkError::Unauthorized,
     )]
     pub queue: Account<'info, Queue>,
 }
@@ -28,5 +31,7 @@ pub fn handler(ctx: Context<QueuePause>) -> Result<()> {
     // Pause the queue
     queue.paused = true;
 
+    emit!(QueuePaused { queue: queue.key() });
+
     Ok(())
 }
@@ -1,9 +1,12 @@
-use {crate::objects::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::*, events::*, objects::*},
+    anchor_lang::prelude::*,
+};
 
 /// Accounts required by the `queue_resume` instruction.
 #[derive(Accounts)]
 pub struct QueueResume<'info> {
-    /// The authority (owner) of the queue.
+    /// The authority (owner) of the queue, or a delegate with permission to resume it.
     #[account()]
     pub authority: Signer<'info>,
 
@@ -12,11 +15,11 @@ pub struct QueueResume<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
-        has_one = authority
+        constraint = queue.has_role(&authority.key(), Role::Pauser) @ ClockworkError::Unauthorized,
     )]
     pub queue: Account<'info, Queue>,
 }
@@ -48,9 +51,60 @@ pub fn handler(ctx: Context<QueueResume>) -> Result<()> {
                 TriggerContext::Immediate => {
                     // Nothing to do
                 }
+                TriggerContext::Slot { started_at: _ } => {
+                    // Nothing to do
+                }
+                TriggerContext::Epoch { started_at: _ } => {
+                    // Jump ahead to the current epoch
+                    queue.exec_context = Some(ExecContext {
+                        trigger_context: TriggerContext::Epoch {
+                            started_at: Clock::get().unwrap().epoch,
+                        },
+                        ..exec_context
+                    });
+                }
+                TriggerContext::Condition { value: _, armed: _ } => {
+                    // Nothing to do
+                }
+                TriggerContext::VersionedAccount {
+                    version: _,
+                    data_hash: _,
+                } => {
+                    // Nothing to do
+                }
+                TriggerContext::Queue { completed_at: _ } => {
+                    // Nothing to do
+                }
+                TriggerContext::Interval { started_at: _ } => {
+                    // Jump ahead to the current timestamp
+                    queue.exec_context = Some(ExecContext {
+                        trigger_context: TriggerContext::Interval {
+                            started_at: Clock::get().unwrap().unix_timestamp,
+                        },
+                        ..exec_context
+                    });
+                }
+                TriggerContext::Timestamp { started_at: _ } => {
+                    // Nothing to do
+                }
+                TriggerContext::BoundedCron {
+                    started_at: _,
+                    runs,
+                } => {
+                    // Jump ahead to the current timestamp
+                    queue.exec_context = Some(ExecContext {
+                        trigger_context: TriggerContext::BoundedCron {
+                            started_at: Clock::get().unwrap().unix_timestamp,
+                            runs,
+                        },
+                        ..exec_context
+                    });
+                }
             }
         }
     }
 
+    emit!(QueueResumed { queue: queue.key() });
+
     Ok(())
 }
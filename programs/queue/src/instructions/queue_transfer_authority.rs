@@ -0,0 +1,77 @@
+use {
+    crate::{events::*, objects::*},
+    anchor_lang::{
+        prelude::*,
+        solana_program::system_program,
+        system_program::{transfer, Transfer},
+    },
+};
+
+/// Accounts required by the `queue_transfer_authority` instruction.
+#[derive(Accounts)]
+#[instruction(new_authority: Pubkey)]
+pub struct QueueTransferAuthority<'info> {
+    /// The authority (owner) of the queue.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The queue to be transferred.
+    #[account(
+        mut,
+        seeds = [
+            SEED_QUEUE,
+            queue.creator.as_ref(),
+            queue.id.as_bytes(),
+        ],
+        bump,
+        has_one = authority,
+    )]
+    pub queue: Account<'info, Queue>,
+
+    /// The Solana system program
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueTransferAuthority>, new_authority: Pubkey) -> Result<()> {
+    // Get accounts
+    let authority = &ctx.accounts.authority;
+    let queue = &mut ctx.accounts.queue;
+    let system_program = &ctx.accounts.system_program;
+
+    // Offer ownership to the new authority. Offering it back to the current authority cancels a pending transfer.
+    queue.pending_authority = if new_authority.eq(&queue.authority) {
+        None
+    } else {
+        Some(new_authority)
+    };
+
+    // Reallocate mem for the queue account
+    queue.realloc()?;
+
+    // If lamports are required to maintain rent-exemption, pay them
+    let data_len = 8 + queue.try_to_vec()?.len();
+    let minimum_rent = Rent::get().unwrap().minimum_balance(data_len);
+    if minimum_rent > queue.to_account_info().lamports() {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: authority.to_account_info(),
+                    to: queue.to_account_info(),
+                },
+            ),
+            minimum_rent
+                .checked_sub(queue.to_account_info().lamports())
+                .unwrap(),
+        )?;
+    }
+
+    emit!(QueueAuthorityTransferStarted {
+        queue: queue.key(),
+        authority: authority.key(),
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
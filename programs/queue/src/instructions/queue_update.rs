@@ -1,5 +1,5 @@
 use {
-    crate::objects::*,
+    crate::{errors::*, objects::*},
     anchor_lang::{
         prelude::*,
         solana_program::system_program,
@@ -11,7 +11,7 @@ use {
 #[derive(Accounts)]
 #[instruction(settings: QueueSettings)]
 pub struct QueueUpdate<'info> {
-    /// The authority (owner) of the queue.
+    /// The authority (owner) of the queue, or a delegate with permission to update it.
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -20,11 +20,11 @@ pub struct QueueUpdate<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
-        has_one = authority,
+        constraint = queue.has_role(&authority.key(), Role::Updater) @ ClockworkError::Unauthorized,
     )]
     pub queue: Account<'info, Queue>,
 
@@ -1,10 +1,13 @@
-use {crate::objects::*, anchor_lang::prelude::*};
+use {
+    crate::{errors::*, objects::*},
+    anchor_lang::prelude::*,
+};
 
 /// Accounts required by the `queue_withdraw` instruction.
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct QueueWithdraw<'info> {
-    /// The authority (owner) of the queue.
+    /// The authority (owner) of the queue, or a delegate with permission to withdraw from it.
     #[account()]
     pub authority: Signer<'info>,
 
@@ -17,11 +20,11 @@ pub struct QueueWithdraw<'info> {
         mut,
         seeds = [
             SEED_QUEUE,
-            queue.authority.as_ref(),
+            queue.creator.as_ref(),
             queue.id.as_bytes(),
         ],
         bump,
-        has_one = authority,
+        constraint = queue.has_role(&authority.key(), Role::Withdrawer) @ ClockworkError::Unauthorized,
     )]
     pub queue: Account<'info, Queue>,
 }
@@ -3,6 +3,7 @@
 //! to schedule transactions and automate smart-contracts without relying on centralized infrastructure.
 
 pub mod errors;
+pub mod events;
 pub mod objects;
 
 mod instructions;
@@ -19,6 +20,11 @@ declare_id!("3XXuUFfweXBwFgFfYaejLvZE4cGZiHgKiGfMtdxNzYmv");
 pub mod queue_program {
     use super::*;
 
+    /// Accepts ownership of a queue which was offered to the signer.
+    pub fn queue_accept_authority(ctx: Context<QueueAcceptAuthority>) -> Result<()> {
+        queue_accept_authority::handler(ctx)
+    }
+
     /// Cranks a transaction queue.
     pub fn queue_crank(ctx: Context<QueueCrank>) -> Result<()> {
         queue_crank::handler(ctx)
@@ -28,10 +34,26 @@ pub mod queue_program {
     pub fn queue_create(
         ctx: Context<QueueCreate>,
         id: String,
-        kickoff_instruction: InstructionData,
+        kickoff_instructions: Vec<InstructionData>,
         trigger: Trigger,
     ) -> Result<()> {
-        queue_create::handler(ctx, id, kickoff_instruction, trigger)
+        queue_create::handler(ctx, id, kickoff_instructions, trigger)
+    }
+
+    /// Grants a role on a queue to a delegate.
+    pub fn queue_delegate_grant(
+        ctx: Context<QueueDelegateGrant>,
+        delegate: Delegate,
+    ) -> Result<()> {
+        queue_delegate_grant::handler(ctx, delegate)
+    }
+
+    /// Revokes a role on a queue from a delegate.
+    pub fn queue_delegate_revoke(
+        ctx: Context<QueueDelegateRevoke>,
+        delegate: Delegate,
+    ) -> Result<()> {
+        queue_delegate_revoke::handler(ctx, delegate)
     }
 
     /// Closes an existing queue account and returns the lamports to the owner.
@@ -39,11 +61,21 @@ pub mod queue_program {
         queue_delete::handler(ctx)
     }
 
+    /// Transfers lamports from a funder to a queue.
+    pub fn queue_fund(ctx: Context<QueueFund>, amount: u64) -> Result<()> {
+        queue_fund::handler(ctx, amount)
+    }
+
     /// Kicks off a queue if its trigger condition is active.
     pub fn queue_kickoff(ctx: Context<QueueKickoff>, data_hash: Option<u64>) -> Result<()> {
         queue_kickoff::handler(ctx, data_hash)
     }
 
+    /// Migrates a queue in a legacy layout to the current layout.
+    pub fn queue_migrate(ctx: Context<QueueMigrate>) -> Result<()> {
+        queue_migrate::handler(ctx)
+    }
+
     /// Pauses an active queue.
     pub fn queue_pause(ctx: Context<QueuePause>) -> Result<()> {
         queue_pause::handler(ctx)
@@ -59,6 +91,14 @@ pub mod queue_program {
         queue_stop::handler(ctx)
     }
 
+    /// Offers ownership of a queue to a new authority, which must accept it to complete the transfer.
+    pub fn queue_transfer_authority(
+        ctx: Context<QueueTransferAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        queue_transfer_authority::handler(ctx, new_authority)
+    }
+
     /// Allows an owner to update the mutable properties of a queue.
     pub fn queue_update(ctx: Context<QueueUpdate>, settings: QueueSettings) -> Result<()> {
         queue_update::handler(ctx, settings)
@@ -0,0 +1,100 @@
+use {
+    super::*,
+    anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize},
+    clockwork_utils::*,
+};
+
+/// The layout of queue accounts created before queues supported multiple instructions.
+/// Queues in this layout cannot be read as a `Queue` until they are migrated with `queue_migrate`.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct QueueV1 {
+    /// The owner of this queue.
+    pub authority: Pubkey,
+    /// The cluster clock at the moment the queue was created.
+    pub created_at: ClockData,
+    /// The context of the current thread execution state.
+    pub exec_context: Option<ExecContext>,
+    /// The number of lamports to payout to workers per crank.
+    pub fee: u64,
+    /// The id of the queue, given by the authority.
+    pub id: String,
+    /// The instruction to kick-off the thread.
+    pub kickoff_instruction: InstructionData,
+    /// The next instruction in the thread.
+    pub next_instruction: Option<InstructionData>,
+    /// Whether or not the queue is currently paused.
+    pub paused: bool,
+    /// The maximum number of cranks allowed per slot.
+    pub rate_limit: u64,
+    /// The triggering event to kickoff a thread.
+    pub trigger: TriggerV1,
+}
+
+impl From<QueueV1> for Queue {
+    fn from(queue: QueueV1) -> Self {
+        Queue {
+            authority: queue.authority,
+            created_at: queue.created_at,
+            exec_context: queue.exec_context,
+            fee: queue.fee,
+            id: queue.id,
+            kickoff_instructions: vec![queue.kickoff_instruction],
+            next_instructions: queue.next_instruction.into_iter().collect(),
+            paused: queue.paused,
+            rate_limit: queue.rate_limit,
+            trigger: queue.trigger.into(),
+            address_lookup_tables: vec![],
+            compute_unit_limit: DEFAULT_COMPUTE_UNIT_LIMIT,
+            compute_unit_price: 0,
+            creator: queue.authority,
+            delegates: vec![],
+            exec_history: vec![],
+            exec_history_limit: 0,
+            max_compute_unit_price: 0,
+            min_balance: 0,
+            pending_authority: None,
+        }
+    }
+}
+
+/// The layout of triggers of queues in the `QueueV1` layout.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, PartialEq)]
+pub enum TriggerV1 {
+    /// Allows a queue to subscribe to an accout and be cranked whenever the data of that account changes.
+    Account {
+        /// The address of the account to subscribe to.
+        pubkey: Pubkey,
+    },
+
+    /// Allows a queue to be cranked according to a one-time or recurring schedule.
+    Cron {
+        /// The schedule in cron syntax. Value must be parsable by the `clockwork_cron` package.
+        schedule: String,
+
+        /// Boolean value indicating whether triggering moments may be skipped if they are missed (e.g. due to network downtime).
+        skippable: bool,
+    },
+
+    /// Allows a queue to be cranked as soon as it's created.
+    Immediate,
+}
+
+impl From<TriggerV1> for Trigger {
+    fn from(trigger: TriggerV1) -> Self {
+        match trigger {
+            TriggerV1::Account { pubkey } => Trigger::Account { pubkey },
+            TriggerV1::Cron {
+                schedule,
+                skippable,
+            } => Trigger::Cron {
+                schedule,
+                skippable,
+                timezone: None,
+                start_at: None,
+                end_at: None,
+                max_runs: None,
+            },
+            TriggerV1::Immediate => Trigger::Immediate,
+        }
+    }
+}
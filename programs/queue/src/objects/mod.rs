@@ -1,5 +1,7 @@
 //! All objects needed to describe and manage the program's state.
 
+mod legacy;
 mod queue;
 
+pub use legacy::*;
 pub use queue::*;
@@ -1,19 +1,23 @@
 use {
-    crate::errors::ClockworkError,
+    crate::{errors::ClockworkError, events::*},
     anchor_lang::{
         prelude::*,
         solana_program::{
+            hash::hashv,
             instruction::Instruction,
             program::{get_return_data, invoke_signed},
+            pubkey,
+            system_instruction::MAX_PERMITTED_DATA_LENGTH,
+            sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
         },
-        AnchorDeserialize, AnchorSerialize,
+        AnchorDeserialize, AnchorSerialize, Discriminator,
     },
     chrono::{DateTime, NaiveDateTime, Utc},
-    clockwork_cron::Schedule,
+    clockwork_cron::{Schedule, Tz},
     clockwork_network_program::objects::{Fee, Penalty, Pool, Worker},
     clockwork_utils::*,
     std::{
-        collections::hash_map::DefaultHasher,
+        cmp::Ordering,
         convert::TryFrom,
         hash::{Hash, Hasher},
         str::FromStr,
@@ -23,22 +27,44 @@ use {
 pub const SEED_QUEUE: &[u8] = b"queue";
 
 /// The default rate limit to initialize queues with
-const DEFAULT_RATE_LIMIT: u64 = 10;
+pub const DEFAULT_RATE_LIMIT: u64 = 10;
 
 /// The maximum rate limit which may be set on queue.
 const MAX_RATE_LIMIT: u64 = 32;
 
 /// The Minimum crank fee that may be set on a queue.
-const MINIMUM_FEE: u64 = 1000;
+pub const MINIMUM_FEE: u64 = 1000;
+
+/// The default compute unit limit to request for crank transactions.
+pub const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// The maximum compute unit limit which may be requested for a transaction.
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// The maximum number of delegates which may be granted roles on a queue.
+pub const MAX_DELEGATES: usize = 16;
+
+/// The maximum number of executions which may be recorded in a queue's history.
+const MAX_EXEC_HISTORY_LIMIT: u64 = 32;
+
+/// The version of the hash function used to compute account trigger data hashes.
+pub const DATA_HASH_VERSION: u8 = 1;
 
 /// The Number of lamports to reimburse the worker with after they've submitted a transaction's worth of cranks.
 const TRANSACTION_BASE_FEE_REIMBURSEMENT: u64 = 5_000;
 
+/// The address of the compute budget program.
+const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// The number of micro-lamports in a lamport.
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
 /// Tracks the current state of a transaction thread on Solana.
 #[account]
 #[derive(Debug)]
 pub struct Queue {
     /// The owner of this queue.
+    /// Kept as the first field, so queues can be filtered by authority at a fixed offset.
     pub authority: Pubkey,
     /// The cluster clock at the moment the queue was created.
     pub created_at: ClockData,
@@ -48,23 +74,154 @@ pub struct Queue {
     pub fee: u64,
     /// The id of the queue, given by the authority.
     pub id: String,
-    /// The instruction to kick-off the thread.
-    pub kickoff_instruction: InstructionData,
-    /// The next instruction in the thread.
-    pub next_instruction: Option<InstructionData>,
+    /// The instructions to kick-off the thread, invoked in order.
+    pub kickoff_instructions: Vec<InstructionData>,
+    /// The next instructions in the thread, invoked in order. If empty, the thread is not currently running.
+    pub next_instructions: Vec<InstructionData>,
     /// Whether or not the queue is currently paused.
     pub paused: bool,
     /// The maximum number of cranks allowed per slot.
     pub rate_limit: u64,
     /// The triggering event to kickoff a thread.
     pub trigger: Trigger,
+    /// The address lookup tables to compress the queue's crank transactions with.
+    pub address_lookup_tables: Vec<Pubkey>,
+    /// The records of the most recent executions, ordered from oldest to newest.
+    pub exec_history: Vec<ExecRecord>,
+    /// The maximum number of execution records to retain in the history. If zero, no history is recorded.
+    pub exec_history_limit: u64,
+    /// The number of lamports the queue must retain, beyond rent-exemption, to continue cranking.
+    pub min_balance: u64,
+    /// The compute unit limit to request for the queue's crank transactions.
+    pub compute_unit_limit: u32,
+    /// The priority fee to pay for the queue's crank transactions, in micro-lamports per compute unit.
+    pub compute_unit_price: u64,
+    /// The maximum compute unit price, in micro-lamports, at which to reimburse workers for priority fees.
+    /// If zero, priority fees are not reimbursed.
+    pub max_compute_unit_price: u64,
+    /// The delegates who may act on the queue on behalf of the authority, with the role they were granted.
+    pub delegates: Vec<Delegate>,
+    /// The authority which created the queue. The queue's address is derived from it, so it never changes.
+    pub creator: Pubkey,
+    /// The account the authority has offered to transfer ownership of the queue to, if any.
+    pub pending_authority: Option<Pubkey>,
 }
 
 impl Queue {
-    /// Derive the pubkey of a queue account.
-    pub fn pubkey(authority: Pubkey, id: String) -> Pubkey {
-        Pubkey::find_program_address(&[SEED_QUEUE, authority.as_ref(), id.as_bytes()], &crate::ID).0
+    /// Derive the pubkey of a queue account from the authority which created it.
+    pub fn pubkey(creator: Pubkey, id: String) -> Pubkey {
+        Pubkey::find_program_address(&[SEED_QUEUE, creator.as_ref(), id.as_bytes()], &crate::ID).0
+    }
+
+    /// Whether or not the given signer may act on the queue with the given role.
+    /// The authority holds every role.
+    pub fn has_role(&self, signer: &Pubkey, role: Role) -> bool {
+        self.authority.eq(signer)
+            || self.delegates.contains(&Delegate {
+                pubkey: *signer,
+                role,
+            })
+    }
+
+    /// Record a new execution in the history, evicting the oldest records beyond the history limit.
+    fn record_exec(&mut self, exec_record: ExecRecord) {
+        self.exec_history.push(exec_record);
+        self.truncate_exec_history();
+    }
+
+    /// Evict the oldest records beyond the history limit.
+    fn truncate_exec_history(&mut self) {
+        let limit = self.exec_history_limit as usize;
+        if self.exec_history.len() > limit {
+            self.exec_history.drain(..self.exec_history.len() - limit);
+        }
+    }
+
+    /// The number of lamports the queue must hold to pay for a crank at the given account size,
+    /// covering rent-exemption, its minimum balance, the crank fee, and the given reimbursements.
+    fn required_balance(&self, rent: &Rent, data_len: usize, reimbursements: u64) -> Result<u64> {
+        rent.minimum_balance(data_len)
+            .checked_add(self.min_balance)
+            .and_then(|required| required.checked_add(self.fee))
+            .and_then(|required| required.checked_add(reimbursements))
+            .ok_or(ClockworkError::InsufficientQueueBalance.into())
+    }
+
+    /// Calculate the priority fee to reimburse the worker with, given the instructions sysvar of the crank transaction.
+    fn priority_fee_reimbursement(&self, instructions: &AccountInfo) -> Result<u64> {
+        if self.max_compute_unit_price == 0 {
+            return Ok(0);
+        }
+
+        // Load the instructions of the transaction.
+        let current_index = load_current_index_checked(instructions)? as usize;
+        let mut transaction_instructions: Vec<Instruction> = vec![];
+        while let Ok(instruction) =
+            load_instruction_at_checked(transaction_instructions.len(), instructions)
+        {
+            transaction_instructions.push(instruction);
+        }
+
+        priority_fee_reimbursement(
+            &transaction_instructions,
+            current_index,
+            self.compute_unit_limit,
+            self.max_compute_unit_price,
+        )
+    }
+}
+
+/// Calculate the priority fee to reimburse for the crank at the current index of a transaction's instructions.
+/// The compute unit limit and price requested by the transaction are bounded by the queue's own limit and maximum price.
+/// A transaction pays its priority fee once, so it is only reimbursed for the first crank in the transaction,
+/// whichever queue that crank is for.
+fn priority_fee_reimbursement(
+    instructions: &[Instruction],
+    current_index: usize,
+    queue_compute_unit_limit: u32,
+    max_compute_unit_price: u64,
+) -> Result<u64> {
+    // If an earlier instruction cranked any queue, the priority fee has already been reimbursed.
+    let is_reimbursed = instructions.iter().take(current_index).any(|instruction| {
+        instruction.program_id.eq(&crate::ID)
+            && instruction
+                .data
+                .starts_with(&crate::instruction::QueueCrank::discriminator())
+    });
+    if is_reimbursed {
+        return Ok(0);
     }
+
+    // Read the compute budget requested by the transaction.
+    let mut compute_unit_limit: Option<u32> = None;
+    let mut compute_unit_price: u64 = 0;
+    for instruction in instructions {
+        if instruction.program_id.eq(&COMPUTE_BUDGET_PROGRAM_ID) {
+            match instruction.data.split_first() {
+                Some((2, data)) => {
+                    compute_unit_limit = u32::try_from_slice(data).ok();
+                }
+                Some((3, data)) => {
+                    compute_unit_price = u64::try_from_slice(data).unwrap_or(0);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Bound the compute budget by the queue's settings.
+    let compute_unit_limit = compute_unit_limit
+        .unwrap_or(queue_compute_unit_limit)
+        .min(queue_compute_unit_limit);
+    let compute_unit_price = compute_unit_price.min(max_compute_unit_price);
+
+    // Convert the priority fee from micro-lamports to lamports, rounding up.
+    (compute_unit_limit as u128)
+        .checked_mul(compute_unit_price as u128)
+        .and_then(|micro_lamports| micro_lamports.checked_add(MICRO_LAMPORTS_PER_LAMPORT - 1))
+        .map(|micro_lamports| micro_lamports / MICRO_LAMPORTS_PER_LAMPORT)
+        .and_then(|priority_fee| u64::try_from(priority_fee).ok())
+        .ok_or(ClockworkError::InvalidPriorityFee.into())
 }
 
 impl TryFrom<Vec<u8>> for Queue {
@@ -76,14 +233,14 @@ impl TryFrom<Vec<u8>> for Queue {
 
 impl Hash for Queue {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.authority.hash(state);
+        self.creator.hash(state);
         self.id.hash(state);
     }
 }
 
 impl PartialEq for Queue {
     fn eq(&self, other: &Self) -> bool {
-        self.authority.eq(&other.authority) && self.id.eq(&other.id)
+        self.creator.eq(&other.creator) && self.id.eq(&other.id)
     }
 }
 
@@ -92,8 +249,14 @@ impl Eq for Queue {}
 /// The properties of queues which are updatable.
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct QueueSettings {
+    pub address_lookup_tables: Option<Vec<Pubkey>>,
+    pub compute_unit_limit: Option<u32>,
+    pub compute_unit_price: Option<u64>,
+    pub exec_history_limit: Option<u64>,
     pub fee: Option<u64>,
-    pub kickoff_instruction: Option<InstructionData>,
+    pub kickoff_instructions: Option<Vec<InstructionData>>,
+    pub max_compute_unit_price: Option<u64>,
+    pub min_balance: Option<u64>,
     pub rate_limit: Option<u64>,
     pub trigger: Option<Trigger>,
 }
@@ -108,24 +271,29 @@ pub trait QueueAccount {
         &mut self,
         authority: Pubkey,
         id: String,
-        kickoff_instruction: InstructionData,
+        kickoff_instructions: Vec<InstructionData>,
         trigger: Trigger,
     ) -> Result<()>;
 
-    /// Crank the queue. Call out to the target program and parse the response for a next instruction.
+    /// Crank the queue. Call out to the target programs and parse the response for the next instructions.
     fn crank(
         &mut self,
         account_infos: &[AccountInfo],
         bump: u8,
         fee: &mut Account<Fee>,
+        instructions: &AccountInfo,
         penalty: &mut Account<Penalty>,
         pool: &Account<Pool>,
         signatory: &mut Signer,
         worker: &Account<Worker>,
     ) -> Result<()>;
 
-    fn kickoff(&mut self, data_hash: Option<u64>, remaining_accounts: &[AccountInfo])
-        -> Result<()>;
+    fn kickoff(
+        &mut self,
+        data_hash: Option<u64>,
+        remaining_accounts: &[AccountInfo],
+        worker: &Account<Worker>,
+    ) -> Result<()>;
 
     /// Reallocate the memory allocation for the account.
     fn realloc(&mut self) -> Result<()>;
@@ -135,24 +303,43 @@ pub trait QueueAccount {
 
 impl QueueAccount for Account<'_, Queue> {
     fn pubkey(&self) -> Pubkey {
-        Queue::pubkey(self.authority, self.id.clone())
+        Queue::pubkey(self.creator, self.id.clone())
     }
 
     fn init(
         &mut self,
         authority: Pubkey,
         id: String,
-        kickoff_instruction: InstructionData,
+        kickoff_instructions: Vec<InstructionData>,
         trigger: Trigger,
     ) -> Result<()> {
+        // Require the queue has at least one kickoff instruction.
+        require!(
+            !kickoff_instructions.is_empty(),
+            ClockworkError::InvalidKickoffInstructions
+        );
+
+        // Validate the trigger.
+        validate_trigger(&trigger)?;
+
         self.authority = authority.key();
+        self.address_lookup_tables = vec![];
+        self.compute_unit_limit = DEFAULT_COMPUTE_UNIT_LIMIT;
+        self.compute_unit_price = 0;
         self.created_at = Clock::get().unwrap().into();
-        self.exec_context = None;
+        self.creator = authority.key();
+        self.delegates = vec![];
+        self.exec_context = initial_exec_context(&trigger);
+        self.exec_history = vec![];
+        self.exec_history_limit = 0;
         self.fee = MINIMUM_FEE;
         self.id = id;
-        self.kickoff_instruction = kickoff_instruction;
-        self.next_instruction = None;
+        self.kickoff_instructions = kickoff_instructions;
+        self.max_compute_unit_price = 0;
+        self.min_balance = 0;
+        self.next_instructions = vec![];
         self.paused = false;
+        self.pending_authority = None;
         self.rate_limit = DEFAULT_RATE_LIMIT;
         self.trigger = trigger;
         Ok(())
@@ -163,71 +350,127 @@ impl QueueAccount for Account<'_, Queue> {
         account_infos: &[AccountInfo],
         bump: u8,
         fee: &mut Account<Fee>,
+        instructions: &AccountInfo,
         penalty: &mut Account<Penalty>,
         pool: &Account<Pool>,
         signatory: &mut Signer,
         worker: &Account<Worker>,
     ) -> Result<()> {
-        // Record the worker's lamports before invoking inner ixs
-        let signatory_lamports_pre = signatory.lamports();
+        // Calculate the bounded priority fee to reimburse the worker with.
+        let priority_fee_reimbursement = self.priority_fee_reimbursement(instructions)?;
+
+        // If the queue cannot cover the crank fee, reimbursements, rent, and its minimum balance, pause it.
+        let rent = Rent::get()?;
+        let transaction_fee_reimbursement = TRANSACTION_BASE_FEE_REIMBURSEMENT
+            .checked_add(priority_fee_reimbursement)
+            .ok_or(ClockworkError::InvalidPriorityFee)?;
+        let balance = self.to_account_info().lamports();
+        let required_balance = self.required_balance(
+            &rent,
+            self.to_account_info().data_len(),
+            transaction_fee_reimbursement,
+        )?;
+        if balance < required_balance {
+            // Reimburse the worker for the base fee of the transaction which paused the queue,
+            // with as many of the lamports the queue holds beyond rent-exemption as it takes.
+            let reimbursement = balance
+                .saturating_sub(rent.minimum_balance(self.to_account_info().data_len()))
+                .min(TRANSACTION_BASE_FEE_REIMBURSEMENT);
+            **self.to_account_info().try_borrow_mut_lamports()? = balance
+                .checked_sub(reimbursement)
+                .ok_or(ClockworkError::InsufficientQueueBalance)?;
+            **signatory.to_account_info().try_borrow_mut_lamports()? = signatory
+                .to_account_info()
+                .lamports()
+                .checked_add(reimbursement)
+                .unwrap();
 
-        // Get the instruction to crank
-        let kickoff_instruction: &InstructionData = &self.clone().kickoff_instruction;
-        let next_instruction: &Option<InstructionData> = &self.clone().next_instruction;
-        let instruction = next_instruction.as_ref().unwrap_or(kickoff_instruction);
-
-        // Inject the signatory's pubkey for the Clockwork payer ID
-        let normalized_accounts: &mut Vec<AccountMeta> = &mut vec![];
-        instruction.accounts.iter().for_each(|acc| {
-            let acc_pubkey = if acc.pubkey == clockwork_utils::PAYER_PUBKEY {
-                signatory.key()
-            } else {
-                acc.pubkey
-            };
-            normalized_accounts.push(AccountMeta {
-                pubkey: acc_pubkey,
-                is_signer: acc.is_signer,
-                is_writable: acc.is_writable,
+            self.paused = true;
+            emit!(QueueAutoPaused {
+                queue: self.key(),
+                balance,
+                required_balance,
             });
-        });
+            return Ok(());
+        }
 
-        // Invoke the provided instruction
-        invoke_signed(
-            &Instruction {
-                program_id: instruction.program_id,
-                data: instruction.data.clone(),
-                accounts: normalized_accounts.to_vec(),
-            },
-            account_infos,
-            &[&[
-                SEED_QUEUE,
-                self.authority.as_ref(),
-                self.id.as_bytes(),
-                &[bump],
-            ]],
-        )?;
+        // Track the lamports paid out of the queue during this crank
+        let mut fees_paid = self.fee;
 
-        // Verify that the inner ix did not write data to the signatory address
-        require!(signatory.data_is_empty(), ClockworkError::UnauthorizedWrite);
+        // Record the worker's lamports before invoking inner ixs
+        let signatory_lamports_pre = signatory.lamports();
 
-        // Parse the crank response
-        match get_return_data() {
-            None => {
-                self.next_instruction = None;
-            }
-            Some((program_id, return_data)) => {
+        // Invoke each of the instructions to crank, in order.
+        let mut crank_response: Option<CrankResponseV2> = None;
+        for instruction in self.next_instructions.clone().iter() {
+            // Inject the signatory's pubkey for the Clockwork payer ID
+            let normalized_accounts: &mut Vec<AccountMeta> = &mut vec![];
+            instruction.accounts.iter().for_each(|acc| {
+                let acc_pubkey = if acc.pubkey == clockwork_utils::PAYER_PUBKEY {
+                    signatory.key()
+                } else {
+                    acc.pubkey
+                };
+                normalized_accounts.push(AccountMeta {
+                    pubkey: acc_pubkey,
+                    is_signer: acc.is_signer,
+                    is_writable: acc.is_writable,
+                });
+            });
+
+            // Invoke the provided instruction
+            invoke_signed(
+                &Instruction {
+                    program_id: instruction.program_id,
+                    data: instruction.data.clone(),
+                    accounts: normalized_accounts.to_vec(),
+                },
+                account_infos,
+                &[&[
+                    SEED_QUEUE,
+                    self.creator.as_ref(),
+                    self.id.as_bytes(),
+                    &[bump],
+                ]],
+            )?;
+
+            // Verify that the inner ix did not write data to the signatory address
+            require!(signatory.data_is_empty(), ClockworkError::UnauthorizedWrite);
+
+            // Parse the crank response, if the instruction returned one.
+            // Programs may return either a single-instruction or a multi-instruction crank response.
+            if let Some((program_id, return_data)) = get_return_data() {
                 require!(
                     program_id.eq(&instruction.program_id),
                     ClockworkError::InvalidCrankResponse
                 );
-                let crank_response = CrankResponse::try_from_slice(return_data.as_slice())
-                    .map_err(|_err| ClockworkError::InvalidCrankResponse)?;
+                crank_response = Some(
+                    CrankResponseV2::try_from_slice(return_data.as_slice())
+                        .or_else(|_err| {
+                            CrankResponse::try_from_slice(return_data.as_slice())
+                                .map(CrankResponseV2::from)
+                        })
+                        .map_err(|_err| ClockworkError::InvalidCrankResponse)?,
+                );
+            }
+        }
 
-                // Update the queue with the crank response.
-                if let Some(kickoff_instruction) = crank_response.kickoff_instruction {
-                    self.kickoff_instruction = kickoff_instruction;
+        // Update the queue with the crank response.
+        let mut kickoff_instructions_updated = false;
+        match crank_response {
+            None => {
+                self.next_instructions = vec![];
+            }
+            Some(crank_response) => {
+                if let Some(kickoff_instructions) = crank_response.kickoff_instructions {
+                    require!(
+                        !kickoff_instructions.is_empty(),
+                        ClockworkError::InvalidCrankResponse
+                    );
+                    self.kickoff_instructions = kickoff_instructions;
+                    kickoff_instructions_updated = true;
                 }
-                self.next_instruction = crank_response.next_instruction;
+                self.next_instructions = crank_response.next_instructions;
             }
         };
 
@@ -261,16 +504,30 @@ impl QueueAccount for Account<'_, Queue> {
         let signatory_reimbursement = signatory_lamports_pre
             .checked_sub(signatory_lamports_post)
             .unwrap();
+
+        // Verify the queue can also cover the signatory reimbursement and the rent of its reallocated account.
+        let required_balance = self.required_balance(
+            &rent,
+            self.to_account_info().data_len(),
+            transaction_fee_reimbursement
+                .checked_add(signatory_reimbursement)
+                .ok_or(ClockworkError::InsufficientQueueBalance)?,
+        )?;
+        require!(
+            self.to_account_info().lamports() >= required_balance,
+            ClockworkError::InsufficientQueueBalance
+        );
         **self.to_account_info().try_borrow_mut_lamports()? = self
             .to_account_info()
             .lamports()
             .checked_sub(signatory_reimbursement)
-            .unwrap();
+            .ok_or(ClockworkError::InsufficientQueueBalance)?;
         **signatory.to_account_info().try_borrow_mut_lamports()? = signatory
             .to_account_info()
             .lamports()
             .checked_add(signatory_reimbursement)
             .unwrap();
+        fees_paid = fees_paid.checked_add(signatory_reimbursement).unwrap();
 
         // Debit the crank fee from the queue account.
         // If the worker is in the pool, pay fee to the worker's fee account.
@@ -279,7 +536,7 @@ impl QueueAccount for Account<'_, Queue> {
             .to_account_info()
             .lamports()
             .checked_sub(self.fee)
-            .unwrap();
+            .ok_or(ClockworkError::InsufficientQueueBalance)?;
         if pool.clone().into_inner().workers.contains(&worker.key()) {
             **fee.to_account_info().try_borrow_mut_lamports()? = fee
                 .to_account_info()
@@ -294,6 +551,21 @@ impl QueueAccount for Account<'_, Queue> {
                 .unwrap();
         }
 
+        // Reimburse the worker for the transaction's priority fee.
+        if priority_fee_reimbursement > 0 {
+            **self.to_account_info().try_borrow_mut_lamports()? = self
+                .to_account_info()
+                .lamports()
+                .checked_sub(priority_fee_reimbursement)
+                .ok_or(ClockworkError::InsufficientQueueBalance)?;
+            **signatory.to_account_info().try_borrow_mut_lamports()? = signatory
+                .to_account_info()
+                .lamports()
+                .checked_add(priority_fee_reimbursement)
+                .unwrap();
+            fees_paid = fees_paid.checked_add(priority_fee_reimbursement).unwrap();
+        }
+
         // If the self has no more work or the number of cranks since the last payout has reached the rate limit,
         // reimburse the worker for the transaction base fee.
         match self.exec_context {
@@ -301,7 +573,7 @@ impl QueueAccount for Account<'_, Queue> {
                 return Err(ClockworkError::InvalidQueueState.into());
             }
             Some(exec_context) => {
-                if self.next_instruction.is_none()
+                if self.next_instructions.is_empty()
                     || exec_context.cranks_since_reimbursement >= self.rate_limit
                 {
                     // Pay reimbursment for base transaction fee
@@ -309,12 +581,15 @@ impl QueueAccount for Account<'_, Queue> {
                         .to_account_info()
                         .lamports()
                         .checked_sub(TRANSACTION_BASE_FEE_REIMBURSEMENT)
-                        .unwrap();
+                        .ok_or(ClockworkError::InsufficientQueueBalance)?;
                     **signatory.to_account_info().try_borrow_mut_lamports()? = signatory
                         .to_account_info()
                         .lamports()
                         .checked_add(TRANSACTION_BASE_FEE_REIMBURSEMENT)
                         .unwrap();
+                    fees_paid = fees_paid
+                        .checked_add(TRANSACTION_BASE_FEE_REIMBURSEMENT)
+                        .unwrap();
 
                     // Update the exec context to mark that a reimbursement happened this slot.
                     self.exec_context = Some(ExecContext {
@@ -325,6 +600,21 @@ impl QueueAccount for Account<'_, Queue> {
             }
         }
 
+        // Record the crank on the current execution in the history.
+        if let Some(exec_record) = self.exec_history.last_mut() {
+            exec_record.cranks = exec_record.cranks.checked_add(1).unwrap();
+            exec_record.fees = exec_record.fees.checked_add(fees_paid).unwrap();
+            exec_record.worker = worker.key();
+        }
+
+        emit!(QueueCranked {
+            queue: self.key(),
+            worker: worker.key(),
+            fees_paid,
+            kickoff_instructions_updated,
+            next_instructions: self.next_instructions.len() as u64,
+        });
+
         Ok(())
     }
 
@@ -336,14 +626,57 @@ impl QueueAccount for Account<'_, Queue> {
     }
 
     fn update(&mut self, settings: QueueSettings) -> Result<()> {
+        // If provided, update the queue's address lookup tables.
+        if let Some(address_lookup_tables) = settings.address_lookup_tables {
+            self.address_lookup_tables = address_lookup_tables;
+        }
+
+        // If provided, update the queue's compute unit limit.
+        if let Some(compute_unit_limit) = settings.compute_unit_limit {
+            require!(
+                compute_unit_limit.gt(&0) && compute_unit_limit.le(&MAX_COMPUTE_UNIT_LIMIT),
+                ClockworkError::InvalidComputeUnitLimit
+            );
+            self.compute_unit_limit = compute_unit_limit;
+        }
+
+        // If provided, update the queue's compute unit price.
+        if let Some(compute_unit_price) = settings.compute_unit_price {
+            self.compute_unit_price = compute_unit_price;
+        }
+
+        // If provided, update the exec history limit and evict records beyond it.
+        if let Some(exec_history_limit) = settings.exec_history_limit {
+            require!(
+                exec_history_limit.le(&MAX_EXEC_HISTORY_LIMIT),
+                ClockworkError::MaxExecHistoryLimitExceeded
+            );
+            self.exec_history_limit = exec_history_limit;
+            self.truncate_exec_history();
+        }
+
         // If provided, update the queue's fee.
         if let Some(fee) = settings.fee {
             self.fee = fee;
         }
 
-        // If provided, update the queue's first instruction
-        if let Some(kickoff_instruction) = settings.kickoff_instruction {
-            self.kickoff_instruction = kickoff_instruction;
+        // If provided, update the queue's kickoff instructions
+        if let Some(kickoff_instructions) = settings.kickoff_instructions {
+            require!(
+                !kickoff_instructions.is_empty(),
+                ClockworkError::InvalidKickoffInstructions
+            );
+            self.kickoff_instructions = kickoff_instructions;
+        }
+
+        // If provided, update the maximum compute unit price to reimburse priority fees at.
+        if let Some(max_compute_unit_price) = settings.max_compute_unit_price {
+            self.max_compute_unit_price = max_compute_unit_price;
+        }
+
+        // If provided, update the queue's minimum balance
+        if let Some(min_balance) = settings.min_balance {
+            self.min_balance = min_balance;
         }
 
         // If provided, update the rate_limit
@@ -358,9 +691,10 @@ impl QueueAccount for Account<'_, Queue> {
         // If provided, update the queue's trigger and reset the exec context
         if let Some(trigger) = settings.trigger {
             // Require the queue is not in the middle of processing.
-            require!(self.next_instruction.is_none(), ClockworkError::QueueBusy);
+            require!(self.next_instructions.is_empty(), ClockworkError::QueueBusy);
+            validate_trigger(&trigger)?;
+            self.exec_context = initial_exec_context(&trigger);
             self.trigger = trigger;
-            self.exec_context = None;
         }
 
         Ok(())
@@ -370,10 +704,14 @@ impl QueueAccount for Account<'_, Queue> {
         &mut self,
         data_hash: Option<u64>,
         remaining_accounts: &[AccountInfo],
+        worker: &Account<Worker>,
     ) -> Result<()> {
+        // Snapshot the queue, in case it cannot afford to be kicked off.
+        let snapshot = (**self).clone();
+
         let clock = Clock::get().unwrap();
         match self.trigger.clone() {
-            Trigger::Account { pubkey } => {
+            Trigger::Account { pubkey } | Trigger::AccountRange { pubkey, .. } => {
                 // Require the provided data hash is non-null.
                 let data_hash = match data_hash {
                     None => return Err(ClockworkError::DataHashNotPresent.into()),
@@ -390,32 +728,35 @@ impl QueueAccount for Account<'_, Queue> {
                             ClockworkError::TriggerNotActive
                         );
 
-                        // Begin computing the data hash of this account.
-                        let mut hasher = DefaultHasher::new();
-                        let data = &account_info.try_borrow_data().unwrap();
-                        data.to_vec().hash(&mut hasher);
+                        // Get the observed account data.
+                        // Verify the byte range being watched, if any, is within the bounds of the account data.
+                        let data = &account_info.try_borrow_data()?;
+                        let observed_data = self
+                            .trigger
+                            .observed_data(data)
+                            .ok_or(ClockworkError::RangeOutOfBounds)?;
 
                         // Check the exec context for the prior data hash.
-                        let expected_data_hash = match self.exec_context.clone() {
+                        // Hashes from legacy account contexts are accepted as a seed, migrating the queue
+                        // to the current hash version on this kickoff.
+                        let prior_data_hash = match self.exec_context.clone() {
                             None => {
                                 // This queue has not begun executing yet.
                                 // There is no prior data hash to include in our hash.
-                                hasher.finish()
+                                None
                             }
-                            Some(exec_context) => {
-                                match exec_context.trigger_context {
-                                    TriggerContext::Account {
-                                        data_hash: prior_data_hash,
-                                    } => {
-                                        // Inject the prior data hash as a seed.
-                                        prior_data_hash.hash(&mut hasher);
-                                        hasher.finish()
-                                    }
-                                    _ => return Err(ClockworkError::InvalidQueueState.into()),
+                            Some(exec_context) => match exec_context.trigger_context {
+                                TriggerContext::Account { data_hash } => Some(data_hash),
+                                TriggerContext::VersionedAccount { data_hash, .. } => {
+                                    Some(data_hash)
                                 }
-                            }
+                                _ => return Err(ClockworkError::InvalidQueueState.into()),
+                            },
                         };
 
+                        // Compute the expected data hash of the observed account data.
+                        let expected_data_hash = hash_account_data(observed_data, prior_data_hash);
+
                         // Verify the data hash provided by the worker is equal to the expected data hash.
                         // This proves the account has been updated since the last crank and the worker has seen the new data.
                         require!(
@@ -428,7 +769,10 @@ impl QueueAccount for Account<'_, Queue> {
                             cranks_since_reimbursement: 0,
                             cranks_since_slot: 0,
                             last_crank_at: clock.slot,
-                            trigger_context: TriggerContext::Account { data_hash },
+                            trigger_context: TriggerContext::VersionedAccount {
+                                version: DATA_HASH_VERSION,
+                                data_hash,
+                            },
                         })
                     }
                 }
@@ -436,24 +780,50 @@ impl QueueAccount for Account<'_, Queue> {
             Trigger::Cron {
                 schedule,
                 skippable,
+                timezone,
+                start_at,
+                end_at,
+                max_runs,
             } => {
-                // Get the reference timestamp for calculating the queue's scheduled target timestamp.
-                let reference_timestamp = match self.exec_context.clone() {
-                    None => self.created_at.unix_timestamp,
+                // Get the reference timestamp for calculating the queue's scheduled target timestamp,
+                // and the number of times the schedule has already kicked off the queue.
+                // Legacy cron contexts did not count runs, so they are migrated with a count of zero.
+                let (reference_timestamp, runs) = match self.exec_context.clone() {
+                    None => (self.created_at.unix_timestamp, 0),
                     Some(exec_context) => match exec_context.trigger_context {
-                        TriggerContext::Cron { started_at } => started_at,
+                        TriggerContext::Cron { started_at } => (started_at, 0),
+                        TriggerContext::BoundedCron { started_at, runs } => (started_at, runs),
                         _ => return Err(ClockworkError::InvalidQueueState.into()),
                     },
                 };
 
+                // Verify the schedule has not exhausted its maximum number of runs.
+                if let Some(max_runs) = max_runs {
+                    require!(runs.lt(&max_runs), ClockworkError::TriggerExpired);
+                }
+
                 // Verify the current timestamp is greater than or equal to the threshold timestamp.
-                let threshold_timestamp = next_timestamp(reference_timestamp, schedule.clone())
-                    .ok_or(ClockworkError::TriggerNotActive)?;
+                // The schedule's first moment may be no earlier than its start timestamp.
+                let reference_timestamp = match start_at {
+                    None => reference_timestamp,
+                    Some(start_at) => reference_timestamp.max(start_at.saturating_sub(1)),
+                };
+                let threshold_timestamp =
+                    next_timestamp(reference_timestamp, schedule.clone(), timezone)
+                        .ok_or(ClockworkError::TriggerNotActive)?;
                 require!(
                     clock.unix_timestamp.ge(&threshold_timestamp),
                     ClockworkError::TriggerNotActive
                 );
 
+                // Verify the threshold timestamp is no later than the schedule's end timestamp.
+                if let Some(end_at) = end_at {
+                    require!(
+                        threshold_timestamp.le(&end_at),
+                        ClockworkError::TriggerExpired
+                    );
+                }
+
                 // If the schedule is marked as skippable, set the started_at of the exec context to be the current timestamp.
                 // Otherwise, the exec context must iterate through each scheduled kickoff moment.
                 let started_at = if skippable {
@@ -467,7 +837,10 @@ impl QueueAccount for Account<'_, Queue> {
                     cranks_since_reimbursement: 0,
                     cranks_since_slot: 0,
                     last_crank_at: clock.slot,
-                    trigger_context: TriggerContext::Cron { started_at },
+                    trigger_context: TriggerContext::BoundedCron {
+                        started_at,
+                        runs: runs.checked_add(1).unwrap(),
+                    },
                 });
             }
             Trigger::Immediate => {
@@ -483,14 +856,251 @@ impl QueueAccount for Account<'_, Queue> {
                     trigger_context: TriggerContext::Immediate,
                 });
             }
+            Trigger::Slot { slot } => {
+                // Verify the cluster has reached the target slot and the queue has not already been kicked off.
+                require!(clock.slot.ge(&slot), ClockworkError::TriggerNotActive);
+                require!(
+                    self.exec_context.is_none(),
+                    ClockworkError::InvalidQueueState
+                );
+
+                // Set the exec context.
+                self.exec_context = Some(ExecContext {
+                    cranks_since_reimbursement: 0,
+                    cranks_since_slot: 0,
+                    last_crank_at: clock.slot,
+                    trigger_context: TriggerContext::Slot {
+                        started_at: clock.slot,
+                    },
+                });
+            }
+            Trigger::Epoch { epoch } => {
+                // Verify the cluster has reached the target epoch and, if the queue has already run,
+                // that a new epoch has started since the last kickoff.
+                require!(clock.epoch.ge(&epoch), ClockworkError::TriggerNotActive);
+                match self.exec_context.clone() {
+                    None => {}
+                    Some(exec_context) => match exec_context.trigger_context {
+                        TriggerContext::Epoch { started_at } => {
+                            require!(
+                                clock.epoch.gt(&started_at),
+                                ClockworkError::TriggerNotActive
+                            )
+                        }
+                        _ => return Err(ClockworkError::InvalidQueueState.into()),
+                    },
+                }
+
+                // Set the exec context.
+                self.exec_context = Some(ExecContext {
+                    cranks_since_reimbursement: 0,
+                    cranks_since_slot: 0,
+                    last_crank_at: clock.slot,
+                    trigger_context: TriggerContext::Epoch {
+                        started_at: clock.epoch,
+                    },
+                });
+            }
+            Trigger::Condition {
+                pubkey,
+                offset,
+                value_type,
+                endianness,
+                operator,
+                operand,
+            } => {
+                // Verify the remaining account is the account this queue is listening for.
+                let account_info = remaining_accounts
+                    .first()
+                    .ok_or(ClockworkError::TriggerNotActive)?;
+                require!(
+                    pubkey.eq(account_info.key),
+                    ClockworkError::TriggerNotActive
+                );
+
+                // Read the watched value from the account data.
+                let data = &account_info.try_borrow_data().unwrap();
+                let value = data
+                    .get(offset as usize..)
+                    .and_then(|data| value_type.read(data, endianness))
+                    .ok_or(ClockworkError::RangeOutOfBounds)?;
+
+                // Check whether the condition has stopped holding since the last kickoff.
+                // A queue which has not been kicked off yet may be kicked off as soon as the condition holds.
+                let armed = match self.exec_context.clone() {
+                    None => true,
+                    Some(exec_context) => match exec_context.trigger_context {
+                        TriggerContext::Condition { armed, .. } => armed,
+                        _ => return Err(ClockworkError::InvalidQueueState.into()),
+                    },
+                };
+
+                // If the condition does not hold for the current value, arm the queue to be kicked off
+                // the next time it does. Otherwise, verify the queue is armed.
+                if !operator.evaluate(value_type.compare(value, operand)) {
+                    match self.exec_context {
+                        Some(exec_context) if !armed => {
+                            self.exec_context = Some(ExecContext {
+                                trigger_context: TriggerContext::Condition { value, armed: true },
+                                ..exec_context
+                            });
+                            return Ok(());
+                        }
+                        _ => return Err(ClockworkError::TriggerNotActive.into()),
+                    }
+                }
+                require!(armed, ClockworkError::TriggerNotActive);
+
+                // Set the exec context.
+                self.exec_context = Some(ExecContext {
+                    cranks_since_reimbursement: 0,
+                    cranks_since_slot: 0,
+                    last_crank_at: clock.slot,
+                    trigger_context: TriggerContext::Condition {
+                        value,
+                        armed: false,
+                    },
+                });
+            }
+            Trigger::Queue { pubkey } => {
+                // Verify the remaining account is the upstream queue this queue depends on.
+                let account_info = remaining_accounts
+                    .first()
+                    .ok_or(ClockworkError::TriggerNotActive)?;
+                require!(
+                    pubkey.eq(account_info.key) && crate::ID.eq(account_info.owner),
+                    ClockworkError::TriggerNotActive
+                );
+                let upstream_queue =
+                    Queue::try_deserialize(&mut account_info.try_borrow_data()?.as_ref())?;
+
+                // Verify the upstream queue has completed a run.
+                // A run is complete once the upstream queue has been kicked off and has no next instructions.
+                let completed_at = match upstream_queue.exec_context {
+                    Some(exec_context) if upstream_queue.next_instructions.is_empty() => {
+                        exec_context.last_crank_at
+                    }
+                    _ => return Err(ClockworkError::TriggerNotActive.into()),
+                };
+
+                // Verify the upstream queue has completed a new run since the last kickoff.
+                match self.exec_context.clone() {
+                    None => {}
+                    Some(exec_context) => match exec_context.trigger_context {
+                        TriggerContext::Queue {
+                            completed_at: prior_completed_at,
+                        } => require!(
+                            completed_at.gt(&prior_completed_at),
+                            ClockworkError::TriggerNotActive
+                        ),
+                        _ => return Err(ClockworkError::InvalidQueueState.into()),
+                    },
+                }
+
+                // Set the exec context.
+                self.exec_context = Some(ExecContext {
+                    cranks_since_reimbursement: 0,
+                    cranks_since_slot: 0,
+                    last_crank_at: clock.slot,
+                    trigger_context: TriggerContext::Queue { completed_at },
+                });
+            }
+            Trigger::Interval { seconds, skippable } => {
+                // Get the reference timestamp for calculating the queue's next target timestamp.
+                let reference_timestamp = match self.exec_context.clone() {
+                    None => self.created_at.unix_timestamp,
+                    Some(exec_context) => match exec_context.trigger_context {
+                        TriggerContext::Interval { started_at } => started_at,
+                        _ => return Err(ClockworkError::InvalidQueueState.into()),
+                    },
+                };
+
+                // Verify the current timestamp is greater than or equal to the threshold timestamp.
+                let threshold_timestamp = reference_timestamp
+                    .checked_add(seconds as i64)
+                    .ok_or(ClockworkError::TriggerNotActive)?;
+                require!(
+                    clock.unix_timestamp.ge(&threshold_timestamp),
+                    ClockworkError::TriggerNotActive
+                );
+
+                // If the interval is marked as skippable, set the started_at of the exec context to be the current timestamp.
+                // Otherwise, the exec context must iterate through each interval.
+                let started_at = if skippable {
+                    clock.unix_timestamp
+                } else {
+                    threshold_timestamp
+                };
+
+                // Set the exec context.
+                self.exec_context = Some(ExecContext {
+                    cranks_since_reimbursement: 0,
+                    cranks_since_slot: 0,
+                    last_crank_at: clock.slot,
+                    trigger_context: TriggerContext::Interval { started_at },
+                });
+            }
+            Trigger::Timestamp { unix_ts } => {
+                // Verify the target timestamp has passed and the queue has not already been kicked off.
+                require!(
+                    clock.unix_timestamp.ge(&unix_ts),
+                    ClockworkError::TriggerNotActive
+                );
+                require!(
+                    self.exec_context.is_none(),
+                    ClockworkError::InvalidQueueState
+                );
+
+                // Set the exec context.
+                self.exec_context = Some(ExecContext {
+                    cranks_since_reimbursement: 0,
+                    cranks_since_slot: 0,
+                    last_crank_at: clock.slot,
+                    trigger_context: TriggerContext::Timestamp {
+                        started_at: unix_ts,
+                    },
+                });
+            }
         }
 
-        // If we make it here, the trigger is active. Update the next instruction and be done.
-        self.next_instruction = Some(self.kickoff_instruction.clone());
+        // If we make it here, the trigger is active. Update the next instructions and be done.
+        self.next_instructions = self.kickoff_instructions.clone();
+
+        // Record the new execution in the history.
+        self.record_exec(ExecRecord {
+            cranks: 0,
+            fees: 0,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            worker: worker.key(),
+        });
+
+        // If the queue cannot cover the rent of its grown account and a crank, pause it instead of kicking it off.
+        let balance = self.to_account_info().lamports();
+        let required_balance = self.required_balance(
+            &Rent::get()?,
+            8 + self.try_to_vec()?.len(),
+            TRANSACTION_BASE_FEE_REIMBURSEMENT,
+        )?;
+        if balance < required_balance {
+            **self = snapshot;
+            self.paused = true;
+            emit!(QueueAutoPaused {
+                queue: self.key(),
+                balance,
+                required_balance,
+            });
+            return Ok(());
+        }
 
         // Realloc the queue account
         self.realloc()?;
 
+        emit!(QueueKickedOff {
+            queue: self.key(),
+            worker: worker.key(),
+        });
+
         Ok(())
     }
 }
@@ -512,10 +1122,206 @@ pub enum Trigger {
         /// Boolean value indicating whether triggering moments may be skipped if they are missed (e.g. due to network downtime).
         /// If false, any "missed" triggering moments will simply be cranked as soon as the network comes back online.
         skippable: bool,
+
+        /// The time zone (e.g. "America/New_York") to evaluate the schedule in, one of those supported by `clockwork_cron::Tz`. Defaults to UTC if not provided.
+        /// Value must be parsable by the `clockwork_cron` package.
+        timezone: Option<String>,
+
+        /// The unix timestamp before which the schedule may not kick off the queue.
+        start_at: Option<i64>,
+
+        /// The unix timestamp after which the schedule may no longer kick off the queue.
+        end_at: Option<i64>,
+
+        /// The maximum number of times the schedule may kick off the queue.
+        max_runs: Option<u64>,
     },
 
     /// Allows a queue to be cranked as soon as it's created.
     Immediate,
+
+    /// Allows a queue to be cranked once the cluster has reached a particular slot.
+    Slot {
+        /// The slot at which the queue should be kicked off.
+        slot: u64,
+    },
+
+    /// Allows a queue to be cranked at the start of every Solana epoch, beginning with the given epoch.
+    Epoch {
+        /// The first epoch the queue may be kicked off in.
+        epoch: u64,
+    },
+
+    /// Allows a queue to be cranked whenever an integer value in an account's data changes and satisfies a condition.
+    Condition {
+        /// The address of the account to subscribe to.
+        pubkey: Pubkey,
+
+        /// The byte offset of the value in the account data.
+        offset: u64,
+
+        /// The integer type of the value.
+        value_type: ValueType,
+
+        /// The byte order the value is encoded in.
+        endianness: Endianness,
+
+        /// The comparison to apply between the value and the operand.
+        operator: Operator,
+
+        /// The operand to compare the value against.
+        operand: i128,
+    },
+
+    /// Allows a queue to be kicked off whenever another queue completes a run.
+    Queue {
+        /// The address of the upstream queue.
+        pubkey: Pubkey,
+    },
+
+    /// Allows a queue to be kicked off repeatedly at a fixed interval.
+    Interval {
+        /// The number of seconds between kickoffs.
+        seconds: u64,
+
+        /// Boolean value indicating whether triggering moments may be skipped if they are missed (e.g. due to network downtime).
+        /// If false, any "missed" triggering moments will simply be kicked off as soon as the network comes back online.
+        skippable: bool,
+    },
+
+    /// Allows a queue to be kicked off once, after a unix timestamp has passed.
+    Timestamp {
+        /// The unix timestamp to kick off the queue at.
+        unix_ts: i64,
+    },
+
+    /// Allows a queue to subscribe to an account and be cranked whenever a byte range of that account's data changes.
+    AccountRange {
+        /// The address of the account to subscribe to.
+        pubkey: Pubkey,
+
+        /// The byte offset of the account data range to watch.
+        offset: u64,
+
+        /// The size of the account data range to watch, in bytes.
+        size: u64,
+    },
+}
+
+impl Trigger {
+    /// The account data observed by an account trigger: the byte range being watched, or otherwise the whole account.
+    /// Returns none if the byte range extends beyond the end of the data.
+    pub fn observed_data<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+        match self {
+            Trigger::AccountRange { offset, size, .. } => {
+                let range_start = usize::try_from(*offset).ok()?;
+                let range_end = range_start.checked_add(usize::try_from(*size).ok()?)?;
+                data.get(range_start..range_end)
+            }
+            _ => Some(data),
+        }
+    }
+}
+
+/// The integer type of a value stored in account data.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, PartialEq)]
+pub enum ValueType {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+}
+
+impl ValueType {
+    /// The size of the value in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            ValueType::U8 | ValueType::I8 => 1,
+            ValueType::U16 | ValueType::I16 => 2,
+            ValueType::U32 | ValueType::I32 => 4,
+            ValueType::U64 | ValueType::I64 => 8,
+            ValueType::U128 | ValueType::I128 => 16,
+        }
+    }
+
+    /// Whether or not the value is a signed integer.
+    pub fn is_signed(&self) -> bool {
+        match self {
+            ValueType::I8 | ValueType::I16 | ValueType::I32 | ValueType::I64 | ValueType::I128 => {
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Read a value of this type from the start of the given bytes.
+    /// Returns the raw bits of the value, sign-extended to 128 bits, or none if there are not enough bytes.
+    pub fn read(&self, data: &[u8], endianness: Endianness) -> Option<u128> {
+        let size = self.size();
+        let bytes = data.get(..size)?;
+        let mut buf = [0u8; 16];
+        match endianness {
+            Endianness::Little => buf[..size].copy_from_slice(bytes),
+            Endianness::Big => {
+                for (i, byte) in bytes.iter().rev().enumerate() {
+                    buf[i] = *byte;
+                }
+            }
+        }
+        if self.is_signed() && buf[size - 1] & 0x80 != 0 {
+            buf[size..].iter_mut().for_each(|byte| *byte = 0xff);
+        }
+        Some(u128::from_le_bytes(buf))
+    }
+
+    /// Compare a value of this type, as returned by `read`, against an operand.
+    pub fn compare(&self, value: u128, operand: i128) -> Ordering {
+        if self.is_signed() {
+            (value as i128).cmp(&operand)
+        } else if operand.is_negative() {
+            Ordering::Greater
+        } else {
+            value.cmp(&(operand as u128))
+        }
+    }
+}
+
+/// The byte order of a value stored in account data.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, PartialEq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+/// A comparison between a value and an operand.
+#[derive(AnchorDeserialize, AnchorSerialize, Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl Operator {
+    /// Evaluate the comparison, given the ordering of the value relative to the operand.
+    pub fn evaluate(&self, ordering: Ordering) -> bool {
+        match self {
+            Operator::Equal => ordering.is_eq(),
+            Operator::NotEqual => ordering.is_ne(),
+            Operator::GreaterThan => ordering.is_gt(),
+            Operator::GreaterThanOrEqual => ordering.is_ge(),
+            Operator::LessThan => ordering.is_lt(),
+            Operator::LessThanOrEqual => ordering.is_le(),
+        }
+    }
 }
 
 /// The execution context of a particular transaction thread.
@@ -534,16 +1340,64 @@ pub struct ExecContext {
     pub trigger_context: TriggerContext,
 }
 
+/// An account which may act on a queue on behalf of its authority.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Delegate {
+    /// The address of the delegate.
+    pub pubkey: Pubkey,
+
+    /// The role the delegate was granted.
+    pub role: Role,
+}
+
+/// The permissions which may be delegated by a queue authority.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Role {
+    /// May pause, resume, and stop the queue.
+    Pauser,
+
+    /// May update the queue's settings.
+    Updater,
+
+    /// May withdraw lamports from the queue.
+    Withdrawer,
+}
+
+/// A record of a past execution of a transaction thread.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ExecRecord {
+    /// Number of cranks processed during the execution.
+    pub cranks: u64,
+
+    /// Number of lamports paid out of the queue to workers during the execution.
+    pub fees: u64,
+
+    /// Slot of the kickoff.
+    pub slot: u64,
+
+    /// Unix timestamp of the kickoff.
+    pub timestamp: i64,
+
+    /// The worker who most recently cranked the execution.
+    pub worker: Pubkey,
+}
+
 /// The event which allowed a particular transaction thread to be triggered.
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum TriggerContext {
     /// A running hash of the observed account data.
+    ///
+    /// Deprecated: these hashes were computed with `DefaultHasher`, which is not stable across Rust releases.
+    /// Queues with this context are migrated to `VersionedAccount` on their next kickoff.
     Account {
         /// The account's data hash.
         data_hash: u64,
     },
 
     /// A cron execution context.
+    ///
+    /// Deprecated: these contexts do not count the schedule's runs.
+    /// Queues with this context are migrated to `BoundedCron` on their next kickoff.
     Cron {
         /// The threshold moment the schedule was waiting for.
         started_at: i64,
@@ -551,15 +1405,288 @@ pub enum TriggerContext {
 
     /// The immediate trigger context.
     Immediate,
+
+    /// A slot execution context.
+    Slot {
+        /// The slot at which the queue was kicked off.
+        started_at: u64,
+    },
+
+    /// An epoch execution context.
+    Epoch {
+        /// The epoch in which the queue was last kicked off.
+        started_at: u64,
+    },
+
+    /// A condition execution context.
+    Condition {
+        /// The raw bits of the value observed at the last kickoff, or when the queue was last armed.
+        value: u128,
+
+        /// Whether the condition has stopped holding since the last kickoff.
+        /// The queue may only be kicked off again once it is armed, so it fires once each time the condition starts to hold.
+        armed: bool,
+    },
+
+    /// A running hash of the observed account data, computed by `hash_account_data`.
+    VersionedAccount {
+        /// The version of the hash function used to compute the data hash.
+        version: u8,
+
+        /// The account's data hash.
+        data_hash: u64,
+    },
+
+    /// The completed run of the upstream queue which kicked off this queue.
+    Queue {
+        /// The slot of the final crank of the upstream queue's run.
+        completed_at: u64,
+    },
+
+    /// An interval execution context.
+    Interval {
+        /// The threshold moment the interval was waiting for.
+        started_at: i64,
+    },
+
+    /// A timestamp execution context.
+    Timestamp {
+        /// The threshold moment the queue was waiting for.
+        started_at: i64,
+    },
+
+    /// A cron execution context, which counts the schedule's runs.
+    BoundedCron {
+        /// The threshold moment the schedule was waiting for.
+        started_at: i64,
+
+        /// The number of times the schedule has kicked off the queue.
+        runs: u64,
+    },
+}
+
+/// Computes the running hash of an account trigger's observed data, seeded with the prior data hash (if any).
+/// The hash is the first 8 bytes of a sha256 digest, so workers and the program agree regardless of toolchain.
+pub fn hash_account_data(data: &[u8], prior_data_hash: Option<u64>) -> u64 {
+    let hash = match prior_data_hash {
+        None => hashv(&[data]),
+        Some(prior_data_hash) => hashv(&[data, &prior_data_hash.to_le_bytes()]),
+    };
+    let mut truncated_hash = [0u8; 8];
+    truncated_hash.copy_from_slice(&hash.to_bytes()[..8]);
+    u64::from_le_bytes(truncated_hash)
 }
 
-fn next_timestamp(after: i64, schedule: String) -> Option<i64> {
-    Schedule::from_str(&schedule)
-        .unwrap()
-        .next_after(&DateTime::<Utc>::from_utc(
-            NaiveDateTime::from_timestamp(after, 0),
-            Utc,
-        ))
-        .take()
-        .map(|datetime| datetime.timestamp())
+fn next_timestamp(after: i64, schedule: String, timezone: Option<String>) -> Option<i64> {
+    let schedule = Schedule::from_str(&schedule).ok()?;
+    let after = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(after, 0), Utc);
+    match timezone {
+        None => schedule
+            .next_after(&after)
+            .map(|datetime| datetime.timestamp()),
+        Some(timezone) => {
+            let timezone = Tz::from_str(&timezone).ok()?;
+            schedule
+                .next_after(&after.with_timezone(&timezone))
+                .map(|datetime| datetime.timestamp())
+        }
+    }
+}
+
+/// The exec context of a queue which has just been initialized with, or updated to, the given trigger.
+/// A queue-triggered queue records the current slot as the upstream run it last ran after,
+/// so it only runs after upstream runs which complete from now on. It has never been cranked,
+/// so its last crank is at slot zero, and it does not look like a completed run to its own dependents.
+fn initial_exec_context(trigger: &Trigger) -> Option<ExecContext> {
+    match trigger {
+        Trigger::Queue { .. } => Some(ExecContext {
+            cranks_since_reimbursement: 0,
+            cranks_since_slot: 0,
+            last_crank_at: 0,
+            trigger_context: TriggerContext::Queue {
+                completed_at: Clock::get().unwrap().slot,
+            },
+        }),
+        _ => None,
+    }
+}
+
+fn validate_trigger(trigger: &Trigger) -> Result<()> {
+    match trigger {
+        Trigger::AccountRange { offset, size, .. } => {
+            let range_end = offset.checked_add(*size);
+            require!(
+                size.gt(&0) && range_end.map_or(false, |end| end.le(&MAX_PERMITTED_DATA_LENGTH)),
+                ClockworkError::InvalidAccountRange
+            );
+        }
+        Trigger::Cron {
+            schedule,
+            timezone,
+            start_at,
+            end_at,
+            max_runs,
+            ..
+        } => {
+            require!(
+                Schedule::from_str(schedule).is_ok(),
+                ClockworkError::InvalidSchedule
+            );
+            if let Some(timezone) = timezone {
+                require!(
+                    Tz::from_str(timezone).is_ok(),
+                    ClockworkError::InvalidTimezone
+                );
+            }
+            if let (Some(start_at), Some(end_at)) = (start_at, end_at) {
+                require!(start_at.le(end_at), ClockworkError::InvalidScheduleBounds);
+            }
+            if let Some(max_runs) = max_runs {
+                require!(max_runs.gt(&0), ClockworkError::InvalidScheduleBounds);
+            }
+        }
+        Trigger::Interval { seconds, .. } => {
+            require!(
+                seconds.gt(&0) && seconds.le(&(i64::MAX as u64)),
+                ClockworkError::InvalidInterval
+            );
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::InstructionData;
+
+    fn crank_instruction(queue_pubkey: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crate::ID,
+            accounts: vec![AccountMeta::new(queue_pubkey, false)],
+            data: crate::instruction::QueueCrank {}.data(),
+        }
+    }
+
+    fn compute_budget_instructions(
+        compute_unit_limit: u32,
+        compute_unit_price: u64,
+    ) -> Vec<Instruction> {
+        vec![
+            Instruction {
+                program_id: COMPUTE_BUDGET_PROGRAM_ID,
+                accounts: vec![],
+                data: [vec![2], compute_unit_limit.to_le_bytes().to_vec()].concat(),
+            },
+            Instruction {
+                program_id: COMPUTE_BUDGET_PROGRAM_ID,
+                accounts: vec![],
+                data: [vec![3], compute_unit_price.to_le_bytes().to_vec()].concat(),
+            },
+        ]
+    }
+
+    fn queue() -> Queue {
+        Queue {
+            authority: Pubkey::new_unique(),
+            created_at: Clock::default().into(),
+            exec_context: None,
+            fee: MINIMUM_FEE,
+            id: "test".into(),
+            kickoff_instructions: vec![],
+            next_instructions: vec![],
+            paused: false,
+            rate_limit: DEFAULT_RATE_LIMIT,
+            trigger: Trigger::Immediate,
+            address_lookup_tables: vec![],
+            exec_history: vec![],
+            exec_history_limit: 1,
+            min_balance: 0,
+            compute_unit_limit: DEFAULT_COMPUTE_UNIT_LIMIT,
+            compute_unit_price: 0,
+            max_compute_unit_price: 0,
+            delegates: vec![],
+            creator: Pubkey::new_unique(),
+            pending_authority: None,
+        }
+    }
+
+    #[test]
+    fn test_barely_funded_queue_cannot_afford_history_growth() {
+        let rent = Rent::default();
+        let mut queue = queue();
+
+        // Fund the queue with exactly the balance required at its current size.
+        let data_len = 8 + queue.try_to_vec().unwrap().len();
+        let balance = queue
+            .required_balance(&rent, data_len, TRANSACTION_BASE_FEE_REIMBURSEMENT)
+            .unwrap();
+
+        // Recording an execution grows the account beyond what the balance can cover.
+        queue.record_exec(ExecRecord {
+            cranks: 0,
+            fees: 0,
+            slot: 0,
+            timestamp: 0,
+            worker: Pubkey::new_unique(),
+        });
+        let data_len = 8 + queue.try_to_vec().unwrap().len();
+        assert!(
+            balance
+                < queue
+                    .required_balance(&rent, data_len, TRANSACTION_BASE_FEE_REIMBURSEMENT)
+                    .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_required_balance_includes_signatory_reimbursement() {
+        let rent = Rent::default();
+        let queue = queue();
+        let data_len = 8 + queue.try_to_vec().unwrap().len();
+        assert_eq!(
+            queue.required_balance(&rent, data_len, 10_000).unwrap(),
+            rent.minimum_balance(data_len) + MINIMUM_FEE + 10_000
+        );
+    }
+
+    #[test]
+    fn test_priority_fee_reimbursed_once_per_packed_tx() {
+        let queue_pubkey = Pubkey::new_unique();
+        let other_queue_pubkey = Pubkey::new_unique();
+        let mut instructions = compute_budget_instructions(200_000, 10_000);
+        instructions.push(crank_instruction(queue_pubkey));
+        instructions.push(crank_instruction(queue_pubkey));
+        instructions.push(crank_instruction(other_queue_pubkey));
+        instructions.push(crank_instruction(queue_pubkey));
+
+        let reimbursements: Vec<u64> = (2..6)
+            .map(|current_index| {
+                priority_fee_reimbursement(&instructions, current_index, 200_000, 10_000).unwrap()
+            })
+            .collect();
+        assert_eq!(reimbursements, vec![2_000, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_priority_fee_reimbursement_is_bounded() {
+        let queue_pubkey = Pubkey::new_unique();
+        let mut instructions = compute_budget_instructions(400_000, 50_000);
+        instructions.push(crank_instruction(queue_pubkey));
+        assert_eq!(
+            priority_fee_reimbursement(&instructions, 2, 200_000, 10_000).unwrap(),
+            2_000
+        );
+    }
+
+    #[test]
+    fn test_priority_fee_reimbursement_overflow() {
+        let queue_pubkey = Pubkey::new_unique();
+        let mut instructions = compute_budget_instructions(MAX_COMPUTE_UNIT_LIMIT, u64::MAX);
+        instructions.push(crank_instruction(queue_pubkey));
+        assert!(
+            priority_fee_reimbursement(&instructions, 2, MAX_COMPUTE_UNIT_LIMIT, u64::MAX).is_err()
+        );
+    }
 }
@@ -13,8 +13,6 @@ use {
     std::{convert::TryFrom, hash::Hash},
 };
 
-// TODO Add support for lookup tables.
-
 /// The stand-in pubkey for delegating a payer address to a worker. All workers are re-imbursed by the user for lamports spent during this delegation.
 pub static PAYER_PUBKEY: Pubkey = static_pubkey!("C1ockworkPayer11111111111111111111111111111");
 
@@ -81,6 +79,7 @@ impl TryFrom<Vec<u8>> for ClockData {
 }
 
 /// A response value target programs can return to update the queue.
+/// If multiple instructions in a crank return a response, the last one takes effect.
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub struct CrankResponse {
     /// The kickoff instruction to use on the next triggering of the queue.
@@ -99,6 +98,37 @@ impl Default for CrankResponse {
     }
 }
 
+/// A response value target programs can return to update the queue with multiple instructions.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct CrankResponseV2 {
+    /// The kickoff instructions to use on the next triggering of the queue.
+    /// If none, the kickoff instructions remain unchanged.
+    pub kickoff_instructions: Option<Vec<InstructionData>>,
+    /// The instructions to invoke, in order, on the next crank of the queue.
+    /// If empty, the queue's current execution is complete.
+    pub next_instructions: Vec<InstructionData>,
+}
+
+impl Default for CrankResponseV2 {
+    fn default() -> Self {
+        return Self {
+            kickoff_instructions: None,
+            next_instructions: vec![],
+        };
+    }
+}
+
+impl From<CrankResponse> for CrankResponseV2 {
+    fn from(crank_response: CrankResponse) -> Self {
+        CrankResponseV2 {
+            kickoff_instructions: crank_response
+                .kickoff_instruction
+                .map(|kickoff_instruction| vec![kickoff_instruction]),
+            next_instructions: crank_response.next_instruction.into_iter().collect(),
+        }
+    }
+}
+
 /// The data needed execute an instruction on Solana.
 #[derive(AnchorDeserialize, AnchorSerialize, BorshSchema, Clone, Debug, Hash, PartialEq)]
 pub struct InstructionData {